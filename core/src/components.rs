@@ -49,6 +49,21 @@ impl<T: IntoView, F: Fn() -> T + Send + Sync + 'static> From<F> for LeptosFormCh
     }
 }
 
+/// Status of the form rendered by a generated form component, provided to its children as context,
+/// e.g. for disabling a submit button passed in `bottom` while the form has errors.
+#[derive(Clone, Copy, Debug)]
+pub struct FormStatus {
+    /// Whether an error is displayed anywhere in the form, see [`crate::FormField::has_errors`].
+    pub has_errors: Signal<bool>,
+    /// Whether the form's action is in flight, e.g. while the files of a multipart form are uploaded.
+    pub submitting: Signal<bool>,
+}
+
+/// The [`FormStatus`] of the enclosing generated form component, if any.
+pub fn use_form_status() -> Option<FormStatus> {
+    use_context()
+}
+
 #[component]
 pub fn FormSubmissionHandler<
     E: Clone + 'static,
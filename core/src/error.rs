@@ -0,0 +1,527 @@
+use ::indexmap::IndexMap;
+use ::leptos::prelude::ServerFnError;
+use ::leptos::server_fn::error::{FromServerFnError, ServerFnErrorErr};
+use ::leptos::server_fn::{Bytes, ContentType, Decodes, Encodes, Format, FormatType};
+use ::std::borrow::Cow;
+use ::std::fmt::{self, Display};
+use ::std::str::FromStr;
+
+/// Error returned while rendering or parsing html form.
+#[derive(Clone, Debug, Error, IsVariant)]
+pub enum FormError {
+    /// The value of a field could not be parsed into its type.
+    #[error("{0}")]
+    Parse(ErrorDetail),
+    /// The value of a field was rejected by a validation rule.
+    #[error("{0}")]
+    Validation(ErrorDetail),
+    /// The value of a field was rejected by the server.
+    #[error("{0}")]
+    Server(ErrorDetail),
+    /// Several errors, e.g. one for each invalid field of a struct.
+    #[error("{0}")]
+    Multiple(FormErrors),
+}
+
+/// Message, machine-readable code and location of a single [`FormError`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErrorDetail {
+    /// Human-readable message, this is what gets rendered next to the field.
+    pub message: String,
+    /// Machine-readable code, e.g. `"min_len"`, usable for looking up a translated message.
+    pub code: Option<Cow<'static, str>>,
+    /// Parameters of the error, e.g. `min = 3` for a `"min_len"` error.
+    pub params: IndexMap<Cow<'static, str>, String>,
+    /// Path of the field the error belongs to, relative to the value `try_from_signal`/`validate` was called on.
+    pub path: FieldPath,
+}
+
+/// Path of a field within a form's value, displayed as e.g. `addresses[2].zip`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FieldPath(pub Vec<PathSegment>);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    /// A named (or positional, for tuple structs) struct field.
+    Field(Cow<'static, str>),
+    /// An item of a collection.
+    Index(usize),
+}
+
+/// Errors returned by a form's action which can be displayed on the fields they refer to,
+/// see the `field_errors` component attribute of Form.
+pub trait ToFormErrors {
+    /// The errors to display, an error whose path does not match any field is displayed at the form level.
+    fn to_form_errors(&self) -> FormErrors;
+}
+
+/// Per-field error messages returned by a server function as its error type.
+///
+/// Fields are addressed by their path, e.g. `addresses[2].zip`; an empty path refers to the whole form.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldErrors(pub IndexMap<String, String>);
+
+/// Collection of single errors, a [`FormErrors`] never contains a [`FormError::Multiple`].
+#[derive(Clone, Debug, Default)]
+pub struct FormErrors(Vec<FormError>);
+
+impl FormError {
+    pub fn parse(err: impl Display) -> Self {
+        Self::Parse(ErrorDetail::new(err))
+    }
+
+    pub fn validation(err: impl Display) -> Self {
+        Self::Validation(ErrorDetail::new(err))
+    }
+
+    pub fn server(err: impl Display) -> Self {
+        Self::Server(ErrorDetail::new(err))
+    }
+
+    pub fn with_code(mut self, code: impl Into<Cow<'static, str>>) -> Self {
+        let code = code.into();
+        self.for_each_detail(&mut |detail| detail.code = Some(code.clone()));
+        self
+    }
+
+    pub fn with_param(mut self, name: impl Into<Cow<'static, str>>, value: impl Display) -> Self {
+        let (name, value) = (name.into(), format!("{value}"));
+        self.for_each_detail(&mut |detail| {
+            detail.params.insert(name.clone(), value.clone());
+        });
+        self
+    }
+
+    /// Prepends the struct field `name` to the path of this error.
+    pub fn in_field(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        let name = name.into();
+        self.for_each_detail(&mut |detail| detail.path.0.insert(0, PathSegment::Field(name.clone())));
+        self
+    }
+
+    /// Prepends the collection index `index` to the path of this error.
+    pub fn at_index(mut self, index: usize) -> Self {
+        self.for_each_detail(&mut |detail| detail.path.0.insert(0, PathSegment::Index(index)));
+        self
+    }
+
+    /// The detail of a single error, `None` for [`FormError::Multiple`].
+    pub fn detail(&self) -> Option<&ErrorDetail> {
+        match self {
+            Self::Parse(detail) | Self::Validation(detail) | Self::Server(detail) => Some(detail),
+            Self::Multiple(_) => None,
+        }
+    }
+
+    pub fn code(&self) -> Option<&str> {
+        self.detail().and_then(|detail| detail.code.as_deref())
+    }
+
+    pub fn path(&self) -> Option<&FieldPath> {
+        self.detail().map(|detail| &detail.path)
+    }
+
+    /// Iterates over the single errors contained in this error, i.e. itself unless it is a [`FormError::Multiple`].
+    pub fn iter(&self) -> ::std::slice::Iter<'_, FormError> {
+        match self {
+            Self::Multiple(errors) => errors.0.iter(),
+            _ => ::std::slice::from_ref(self).iter(),
+        }
+    }
+
+    /// Replaces the path of this error.
+    pub fn with_path(mut self, path: FieldPath) -> Self {
+        self.for_each_detail(&mut |detail| detail.path = path.clone());
+        self
+    }
+
+    fn for_each_detail(&mut self, f: &mut dyn FnMut(&mut ErrorDetail)) {
+        match self {
+            Self::Parse(detail) | Self::Validation(detail) | Self::Server(detail) => f(detail),
+            Self::Multiple(errors) => errors.0.iter_mut().for_each(|error| error.for_each_detail(f)),
+        }
+    }
+}
+
+impl ErrorDetail {
+    pub fn new(message: impl Display) -> Self {
+        Self {
+            message: format!("{message}"),
+            ..Default::default()
+        }
+    }
+}
+
+impl Display for ErrorDetail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl FieldPath {
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{name}")?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for FieldPath {
+    type Err = FormError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || FormError::parse(format!("invalid field path `{s}`"));
+        let mut segments = vec![];
+        let mut rest = s;
+        while !rest.is_empty() {
+            if let Some(index) = rest.strip_prefix('[') {
+                let (index, after) = index.split_once(']').ok_or_else(invalid)?;
+                segments.push(PathSegment::Index(index.parse().map_err(|_| invalid())?));
+                rest = after;
+                continue;
+            }
+            let name = match segments.is_empty() {
+                true => rest,
+                false => rest.strip_prefix('.').ok_or_else(invalid)?,
+            };
+            let end = name.find(['.', '[']).unwrap_or(name.len());
+            if end == 0 {
+                return Err(invalid());
+            }
+            segments.push(PathSegment::Field(Cow::Owned(name[..end].to_string())));
+            rest = &name[end..];
+        }
+        Ok(Self(segments))
+    }
+}
+
+impl FormErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, error: FormError) {
+        match error {
+            FormError::Multiple(errors) => self.0.extend(errors.0),
+            error => self.0.push(error),
+        }
+    }
+
+    /// Records the error of `result` if any, otherwise returns its value.
+    pub fn record<T>(&mut self, result: Result<T, FormError>) -> Option<T> {
+        result.map_err(|err| self.push(err)).ok()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> ::std::slice::Iter<'_, FormError> {
+        self.0.iter()
+    }
+
+    /// `Ok` if no error was recorded, otherwise the recorded error(s) as a single [`FormError`].
+    pub fn into_result(self) -> Result<(), FormError> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(self.into()),
+        }
+    }
+}
+
+impl Display for FormErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            match error.path() {
+                Some(path) if !path.is_root() => write!(f, "{path}: {error}")?,
+                _ => write!(f, "{error}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<FormErrors> for FormError {
+    fn from(mut errors: FormErrors) -> Self {
+        match errors.0.len() {
+            1 => errors.0.remove(0),
+            _ => Self::Multiple(errors),
+        }
+    }
+}
+
+impl From<FormError> for FormErrors {
+    fn from(error: FormError) -> Self {
+        let mut errors = Self::new();
+        errors.push(error);
+        errors
+    }
+}
+
+impl Extend<FormError> for FormErrors {
+    fn extend<I: IntoIterator<Item = FormError>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|error| self.push(error))
+    }
+}
+
+impl FromIterator<FormError> for FormErrors {
+    fn from_iter<I: IntoIterator<Item = FormError>>(iter: I) -> Self {
+        let mut errors = Self::new();
+        errors.extend(iter);
+        errors
+    }
+}
+
+impl IntoIterator for FormErrors {
+    type Item = FormError;
+    type IntoIter = ::std::vec::IntoIter<FormError>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a FormErrors {
+    type Item = &'a FormError;
+    type IntoIter = ::std::slice::Iter<'a, FormError>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl FieldErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an error displayed on the field at `path`, e.g. `addresses[2].zip`.
+    pub fn with_field(mut self, path: impl Into<String>, message: impl Display) -> Self {
+        self.0.insert(path.into(), format!("{message}"));
+        self
+    }
+
+    /// Adds an error displayed at the form level.
+    pub fn with_form(self, message: impl Display) -> Self {
+        self.with_field("", message)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+// encoded as one `path<TAB>message` line per error so that `FieldErrors` can be sent back from a server function
+impl Display for FieldErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let escape = |x: &str| {
+            x.replace('\\', "\\\\")
+                .replace('\n', "\\n")
+                .replace('\r', "\\r")
+                .replace('\t', "\\t")
+        };
+        for (i, (path, message)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{}\t{}", escape(path), escape(message))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for FieldErrors {
+    type Err = FormError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unescape = |x: &str| {
+            let mut unescaped = String::with_capacity(x.len());
+            let mut chars = x.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => match chars.next() {
+                        Some('n') => unescaped.push('\n'),
+                        Some('r') => unescaped.push('\r'),
+                        Some('t') => unescaped.push('\t'),
+                        Some(c) => unescaped.push(c),
+                        None => unescaped.push('\\'),
+                    },
+                    c => unescaped.push(c),
+                }
+            }
+            unescaped
+        };
+        s.lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (path, message) = line
+                    .split_once('\t')
+                    .ok_or_else(|| FormError::parse(format!("invalid field error `{line}`")))?;
+                Ok((unescape(path), unescape(message)))
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl ToFormErrors for FieldErrors {
+    fn to_form_errors(&self) -> FormErrors {
+        self.0
+            .iter()
+            .map(|(path, message)| {
+                let error = FormError::server(message);
+                match path.parse() {
+                    Ok(path) => error.with_path(path),
+                    Err(_) => error,
+                }
+            })
+            .collect()
+    }
+}
+
+impl ToFormErrors for FormError {
+    fn to_form_errors(&self) -> FormErrors {
+        self.clone().into()
+    }
+}
+
+impl ToFormErrors for FormErrors {
+    fn to_form_errors(&self) -> FormErrors {
+        self.clone()
+    }
+}
+
+impl<E: Display> ToFormErrors for ServerFnError<E> {
+    fn to_form_errors(&self) -> FormErrors {
+        FormError::server(self).into()
+    }
+}
+
+/// Failures of the server function itself (e.g. a network error) are displayed at the form level.
+impl FromServerFnError for FieldErrors {
+    type Encoder = FieldErrorsEncoding;
+
+    fn from_server_fn_error(value: ServerFnErrorErr) -> Self {
+        Self::new().with_form(value)
+    }
+}
+
+/// Encodes [`FieldErrors`] as the text of their [`Display`] impl when they are returned by a server function.
+#[derive(Clone, Copy, Debug)]
+pub struct FieldErrorsEncoding;
+
+impl ContentType for FieldErrorsEncoding {
+    const CONTENT_TYPE: &'static str = "text/plain";
+}
+
+impl FormatType for FieldErrorsEncoding {
+    const FORMAT_TYPE: Format = Format::Text;
+}
+
+impl Encodes<FieldErrors> for FieldErrorsEncoding {
+    type Error = fmt::Error;
+
+    fn encode(output: &FieldErrors) -> Result<Bytes, Self::Error> {
+        Ok(Bytes::from(output.to_string()))
+    }
+}
+
+impl Decodes<FieldErrors> for FieldErrorsEncoding {
+    type Error = FormError;
+
+    fn decode(bytes: Bytes) -> Result<FieldErrors, Self::Error> {
+        String::from_utf8_lossy(&bytes).parse()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn field_paths_are_parsed() {
+        let path = "addresses[2].zip".parse::<FieldPath>().unwrap();
+        assert_eq!(
+            FieldPath(vec![
+                PathSegment::Field("addresses".into()),
+                PathSegment::Index(2),
+                PathSegment::Field("zip".into()),
+            ]),
+            path
+        );
+        assert_eq!("addresses[2].zip", path.to_string());
+        assert!("".parse::<FieldPath>().unwrap().is_root());
+        for path in ["[0].name", "matrix[1][2]", "_0.value"] {
+            assert_eq!(path, path.parse::<FieldPath>().unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn invalid_field_paths_are_rejected() {
+        for path in [".name", "a..b", "a.", "a[", "a[x]", "a[-1]", "a[0]b"] {
+            assert!(path.parse::<FieldPath>().unwrap_err().is_parse(), "{path}");
+        }
+    }
+
+    #[test]
+    fn field_errors_are_encoded_one_per_line() {
+        let errors = FieldErrors::new()
+            .with_field("username", "is taken")
+            .with_form("try again\tlater\n(or not)");
+        assert_eq!("username\tis taken\n\ttry again\\tlater\\n(or not)", errors.to_string());
+    }
+
+    #[test]
+    fn field_errors_round_trip() {
+        let errors = FieldErrors::new()
+            .with_field("addresses[2].zip", "tab\there")
+            .with_field("a\tpath", "line\r\nbreak")
+            .with_field("backslashes", "C:\\new\\n")
+            .with_form("");
+        assert_eq!(errors, errors.to_string().parse::<FieldErrors>().unwrap());
+        assert_eq!(FieldErrors::new(), "".parse::<FieldErrors>().unwrap());
+        assert_eq!(
+            FieldErrors::new().with_field("name", "x"),
+            "\n\nname\tx\n".parse::<FieldErrors>().unwrap()
+        );
+        assert!("no tab".parse::<FieldErrors>().unwrap_err().is_parse());
+    }
+
+    #[test]
+    fn field_errors_are_sent_back_from_server_functions() {
+        let errors = FieldErrors::new().with_field("email", "already\tregistered");
+        let bytes = FieldErrorsEncoding::encode(&errors).unwrap();
+        assert_eq!(errors, FieldErrorsEncoding::decode(bytes).unwrap());
+        let failure = FieldErrors::from_server_fn_error(ServerFnErrorErr::Request("offline".to_string()));
+        assert_eq!(1, failure.0.len());
+        assert!(failure.0.contains_key(""));
+    }
+
+    #[test]
+    fn field_errors_are_displayed_on_their_fields() {
+        let errors = FieldErrors::new()
+            .with_field("addresses[2].zip", "is unknown")
+            .with_field("not..a path", "is displayed on the form")
+            .to_form_errors();
+        assert_eq!(2, errors.len());
+        assert!(errors.iter().all(|error| error.is_server()));
+        assert_eq!(
+            "addresses[2].zip: is unknown; is displayed on the form",
+            errors.to_string()
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use super::config::collections::*;
+use crate::*;
+use ::leptos::prelude::*;
+use ::std::array;
+
+impl<T: DefaultHtmlElement, const N: usize> DefaultHtmlElement for [T; N] {
+    type El = [T::El; N];
+}
+
+// arrays are configured with the same config as a Vec, ignoring its `size`, `add` and `remove` fields
+// since the number of items is fixed to N
+impl<T, El, const N: usize> FormField<[El; N]> for [T; N]
+where
+    T: FormField<El>,
+{
+    type Config = VecConfig<<T as FormField<El>>::Config>;
+    type Signal = [<T as FormField<El>>::Signal; N];
+
+    fn default_signal(config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+        match initial {
+            Some(initial) => initial.map(|initial| T::default_signal(&config.item, Some(initial))),
+            None => array::from_fn(|_| T::default_signal(&config.item, None)),
+        }
+    }
+    fn is_default_value(signal: &Self::Signal) -> bool {
+        signal.iter().all(T::is_default_value)
+    }
+    fn into_signal(self, config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+        let mut items = self.into_iter().zip(
+            initial
+                .map(|initial| initial.map(Some))
+                .unwrap_or_else(|| array::from_fn(|_| None)),
+        );
+        array::from_fn(|_| {
+            let (item, initial) = items
+                .next()
+                .expect("array items are zipped with an array of the same length");
+            item.into_signal(&config.item, initial)
+        })
+    }
+    fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+        let mut errors = FormErrors::new();
+        let items = signal
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                errors.record(T::try_from_signal(item, &config.item).map_err(|err| err.at_index(i)))
+            })
+            .collect::<Vec<_>>();
+        errors.into_result()?;
+        Ok(items
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("every item was parsed successfully")))
+    }
+    fn recurse(signal: &Self::Signal) {
+        signal.iter().for_each(T::recurse)
+    }
+    fn reset_initial_value(signal: &Self::Signal) {
+        signal.iter().for_each(T::reset_initial_value)
+    }
+    fn has_changed(signal: &Self::Signal) -> bool {
+        signal.iter().any(T::has_changed)
+    }
+    fn validate(signal: &Self::Signal, config: &Self::Config) -> Result<(), FormError> {
+        let mut errors = FormErrors::new();
+        for (i, item) in signal.iter().enumerate() {
+            errors.record(T::validate(item, &config.item).map_err(|err| err.at_index(i)));
+        }
+        errors.into_result()
+    }
+    fn set_error_at(signal: &Self::Signal, path: &[PathSegment], error: FormError) -> Result<(), FormError> {
+        match path.split_first() {
+            Some((PathSegment::Index(i), path)) => match signal.get(*i) {
+                Some(item) => T::set_error_at(item, path, error),
+                None => Err(error),
+            },
+            _ => Err(error),
+        }
+    }
+    fn collect_errors(signal: &Self::Signal, errors: &mut FormErrors) {
+        for (i, item) in signal.iter().enumerate() {
+            errors.extend(T::errors(item).into_iter().map(|err| err.at_index(i)));
+        }
+    }
+    fn is_pending(signal: &Self::Signal) -> bool {
+        signal.iter().any(T::is_pending)
+    }
+    fn is_touched(signal: &Self::Signal) -> bool {
+        signal.iter().any(T::is_touched)
+    }
+    fn touch(signal: &Self::Signal) {
+        signal.iter().for_each(T::touch)
+    }
+    fn redact(&mut self) {
+        self.iter_mut().for_each(T::redact)
+    }
+}
+
+impl<T, El, const N: usize> FormComponent<[El; N]> for [T; N]
+where
+    T: FormComponent<El>,
+{
+    fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+        let VecConfig {
+            item: item_config,
+            item_container_class,
+            item_class,
+            item_label,
+            item_style,
+            ..
+        } = props.config;
+
+        let items = props
+            .signal
+            .into_iter()
+            .enumerate()
+            .map(|(index, signal)| {
+                let id: Oco<'static, str> = Oco::Owned(index.to_string());
+                let label = item_label.as_ref().and_then(|item_label| {
+                    let notation = item_label.notation?;
+                    Some(notation.render(index) + item_label.punctuation.map(|x| x.render()).unwrap_or_default())
+                });
+
+                let item_props = RenderProps::builder()
+                    .id(id.clone())
+                    .name(crate::format_form_name(props.name.as_ref(), index.to_string()))
+                    .class(item_class.clone())
+                    .style(item_style.clone())
+                    .field_changed_class(props.field_changed_class.clone())
+                    .on_change(props.on_change.clone())
+                    .validate_on(props.validate_on)
+                    .signal(signal)
+                    .config(item_config.clone())
+                    .build();
+
+                wrap_item(
+                    item_container_class.clone(),
+                    item_label.as_ref().and_then(|item_label| item_label.class.clone()),
+                    item_label.as_ref().and_then(|item_label| item_label.style.clone()),
+                    label,
+                    id,
+                    <T as FormComponent<El>>::render(item_props),
+                )
+            })
+            .collect_view();
+
+        view! {
+            <div id={props.id} class={props.class} style={props.style}>
+                {items}
+            </div>
+        }
+    }
+}
+
+/// Renders one of the fixed items of an array or tuple, prefixed with its label if it has one.
+pub(super) fn wrap_item(
+    container_class: Option<Oco<'static, str>>,
+    label_class: Option<Oco<'static, str>>,
+    label_style: Option<Oco<'static, str>>,
+    label: Option<String>,
+    id: Oco<'static, str>,
+    item: impl IntoView + 'static,
+) -> impl IntoView {
+    view! {
+        <div class={container_class} style="display: flex; flex-direction: row; align-items: center; margin-bottom: 0.5rem">
+            {match label {
+                Some(label) => view! {
+                    <label for={id} class={label_class} style={label_style}>
+                        <div>{label}</div>
+                        {item}
+                    </label>
+                }
+                .into_any(),
+                None => item.into_any(),
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,212 @@
+use crate::*;
+use ::leptos::html::*;
+use ::leptos::prelude::*;
+use ::wasm_bindgen::JsValue;
+
+/// Configuration for `bool` fields.
+///
+/// Labels are only used when the field is optional (i.e. `Option<bool>`), which is rendered as a tri-state
+/// select instead of a checkbox.
+#[derive(Clone, Debug)]
+pub struct BoolConfig {
+    /// defaults to `""`
+    pub unset_label: Oco<'static, str>,
+    /// defaults to `"Yes"`
+    pub true_label: Oco<'static, str>,
+    /// defaults to `"No"`
+    pub false_label: Oco<'static, str>,
+}
+
+impl Default for BoolConfig {
+    fn default() -> Self {
+        Self {
+            unset_label: Oco::Borrowed(""),
+            true_label: Oco::Borrowed("Yes"),
+            false_label: Oco::Borrowed("No"),
+        }
+    }
+}
+
+impl DefaultHtmlElement for bool {
+    type El = HtmlElement<Input, (), ()>;
+}
+
+// the signal holds an `Option<bool>` so that `Option<bool>` can tell an unset value apart from `false`,
+// a `bool` which was never set is parsed as `false` just like an unchecked checkbox
+impl FormField<HtmlElement<Input, (), ()>> for bool {
+    type Config = BoolConfig;
+    type Signal = FormFieldSignal<Option<bool>>;
+
+    fn default_signal(_: &Self::Config, initial: Option<Self>) -> Self::Signal {
+        FormFieldSignal::new_with_default_value(initial.map(Some))
+    }
+    fn is_default_value(signal: &Self::Signal) -> bool {
+        signal.value.with_untracked(|value| value.is_none())
+    }
+    fn into_signal(self, _: &Self::Config, initial: Option<Self>) -> Self::Signal {
+        FormFieldSignal::new(Some(self), initial.map(Some))
+    }
+    fn try_from_signal(signal: Self::Signal, _: &Self::Config) -> Result<Self, FormError> {
+        Ok(signal.value.with_untracked(|value| value.unwrap_or_default()))
+    }
+    fn recurse(signal: &Self::Signal) {
+        signal.value.with_untracked(|_| {})
+    }
+    fn reset_initial_value(signal: &Self::Signal) {
+        signal
+            .value
+            .with_untracked(|value| signal.initial.update(|initial| *initial = Some(*value)));
+    }
+    fn has_changed(signal: &Self::Signal) -> bool {
+        signal.has_changed_tracked()
+    }
+    fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
+        signal.error.with(|error| f(error.as_ref()))
+    }
+    fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+        signal.error.set(error)
+    }
+    fn is_pending(signal: &Self::Signal) -> bool {
+        signal.pending.get()
+    }
+    fn set_pending(signal: &Self::Signal, pending: bool) {
+        signal.pending.set(pending)
+    }
+    fn is_touched(signal: &Self::Signal) -> bool {
+        signal.touched.get()
+    }
+    fn touch(signal: &Self::Signal) {
+        signal.touched.set(true)
+    }
+}
+
+fn tri_state_to_str(value: Option<bool>) -> &'static str {
+    match value {
+        Some(true) => "true",
+        Some(false) => "false",
+        None => "",
+    }
+}
+
+fn tri_state_from_str(value: &str) -> Option<bool> {
+    value.parse().ok()
+}
+
+#[cfg(feature = "thaw")]
+impl FormComponent<HtmlElement<Input, (), ()>> for bool {
+    fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+        let class = props.class_signal();
+        let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
+        let value = props.signal.value;
+        let id = props.id.clone().or_else(|| props.name.clone()).map(|s| s.to_string());
+
+        match props.is_optional {
+            true => {
+                let selected = RwSignal::new(tri_state_to_str(value.get_untracked()).to_string());
+                Effect::new(move |_| selected.set(tri_state_to_str(value.get()).to_string()));
+                view! {
+                    <thaw::Select
+                        class={class.get().map(|s| s.to_string())}
+                        id=id
+                        name={props.name.map(|s| s.to_string())}
+                        on:input=move |ev| {
+                            value.set(tri_state_from_str(&event_target_value(&ev)));
+                            events.handle(ValidateOn::Input)
+                        }
+                        on:change=move |_| events.handle(ValidateOn::Change)
+                        on:focusout=move |_| events.handle(ValidateOn::Blur)
+                        value=selected
+                    >
+                        <option value="">{props.config.unset_label.to_string()}</option>
+                        <option value="true">{props.config.true_label.to_string()}</option>
+                        <option value="false">{props.config.false_label.to_string()}</option>
+                    </thaw::Select>
+                }
+                .into_any()
+            }
+            false => {
+                let checked = RwSignal::new(value.get_untracked().unwrap_or_default());
+                Effect::new(move |_| checked.set(value.get().unwrap_or_default()));
+                // unchecked checkboxes are left out of submissions, so the value is submitted by a hidden input instead
+                view! {
+                    <input
+                        type="hidden"
+                        name={props.name.map(|s| s.to_string())}
+                        prop:value=move || value.get().unwrap_or_default().to_string()
+                    />
+                    <thaw::Switch
+                        class={class.get().map(|s| s.to_string())}
+                        id=id
+                        on:change=move |ev| {
+                            value.set(Some(event_target_checked(&ev)));
+                            events.handle(ValidateOn::Input);
+                            events.handle(ValidateOn::Change)
+                        }
+                        on:focusout=move |_| events.handle(ValidateOn::Blur)
+                        checked=checked
+                    />
+                }
+                .into_any()
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "thaw"))]
+impl FormComponent<HtmlElement<Input, (), ()>> for bool {
+    fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+        let class = props.class_signal();
+        let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
+        let value = props.signal.value;
+
+        match props.is_optional {
+            true => view! {
+                <select
+                    class={class}
+                    id={props.id.or_else(|| props.name.clone())}
+                    name={props.name}
+                    required=props.input_attrs.required
+                    autocomplete=props.input_attrs.autocomplete
+                    on:input=move |ev| {
+                        value.set(tri_state_from_str(&event_target_value(&ev)));
+                        events.handle(ValidateOn::Input)
+                    }
+                    on:change=move |_| events.handle(ValidateOn::Change)
+                    on:focusout=move |_| events.handle(ValidateOn::Blur)
+                    prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                    prop:value=move || tri_state_to_str(value.get())
+                    style={props.style}
+                >
+                    <option value="" selected=move || value.get().is_none()>{props.config.unset_label.to_string()}</option>
+                    <option value="true" selected=move || value.get() == Some(true)>{props.config.true_label.to_string()}</option>
+                    <option value="false" selected=move || value.get() == Some(false)>{props.config.false_label.to_string()}</option>
+                </select>
+            }
+            .into_any(),
+            // unchecked checkboxes are left out of submissions, so the value is submitted by a hidden input instead
+            false => view! {
+                <input
+                    type="hidden"
+                    name={props.name.clone()}
+                    value=move || value.get().unwrap_or_default().to_string()
+                />
+                <input
+                    type="checkbox"
+                    class={class}
+                    id={props.id.or(props.name)}
+                    required=props.input_attrs.required
+                    on:input=move |ev| {
+                        value.set(Some(event_target_checked(&ev)));
+                        events.handle(ValidateOn::Input)
+                    }
+                    on:change=move |_| events.handle(ValidateOn::Change)
+                    on:focusout=move |_| events.handle(ValidateOn::Blur)
+                    prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                    prop:checked=move || value.get().unwrap_or_default()
+                    style={props.style}
+                />
+            }
+            .into_any(),
+        }
+    }
+}
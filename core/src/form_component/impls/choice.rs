@@ -0,0 +1,323 @@
+use crate::*;
+use ::leptos::html::*;
+use ::leptos::prelude::*;
+use ::wasm_bindgen::JsValue;
+
+/// Values which can be picked from a list of options, see [`ChoiceConfig`].
+///
+/// Implement this for your own types (e.g. an enum of plans) in order to render them
+/// using `#[form(el(HtmlElement<Select, (), ()>))]` or `#[form(el(RadioGroup))]`.
+pub trait Choice: Clone + PartialEq + Send + Sync + 'static {
+    /// The `value` attribute of this choice's option.
+    fn to_choice(&self) -> String;
+    /// Parses the `value` attribute of the selected option.
+    fn from_choice(value: &str) -> Result<Self, FormError>;
+}
+
+/// Element type rendering a field as a group of radio inputs, one per option of its [`ChoiceConfig`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RadioGroup;
+
+/// The options a [`Choice`] field can be set to, as `(value, label)` pairs.
+#[derive(Clone, Debug)]
+pub enum ChoiceOptions<T: Send + Sync + 'static> {
+    Static(Vec<(T, Oco<'static, str>)>),
+    Signal(Signal<Vec<(T, Oco<'static, str>)>>),
+}
+
+/// Configuration for fields rendered as a select or a radio group.
+#[derive(Clone, Debug)]
+pub struct ChoiceConfig<T: Send + Sync + 'static> {
+    pub options: ChoiceOptions<T>,
+    /// Label of an empty option shown before the others when the field is rendered as a select;
+    /// optional fields always have an empty option
+    pub placeholder: Option<Oco<'static, str>>,
+}
+
+impl<T: Send + Sync + 'static> Default for ChoiceOptions<T> {
+    fn default() -> Self {
+        Self::Static(vec![])
+    }
+}
+
+impl<T: Send + Sync + 'static> Default for ChoiceConfig<T> {
+    fn default() -> Self {
+        Self {
+            options: Default::default(),
+            placeholder: None,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> ChoiceOptions<T> {
+    /// Options loaded by a resource, e.g. one calling a server function; there are no options while it is loading.
+    pub fn resource<R>(resource: R) -> Self
+    where
+        T: Clone,
+        R: Get<Value = Option<Vec<(T, Oco<'static, str>)>>> + Send + Sync + 'static,
+    {
+        Self::Signal(Signal::derive(move || resource.get().unwrap_or_default()))
+    }
+
+    /// Calls `f` with the current options, subscribing to them if they come from a signal.
+    pub fn with<O>(&self, f: impl FnOnce(&[(T, Oco<'static, str>)]) -> O) -> O {
+        match self {
+            Self::Static(options) => f(options),
+            Self::Signal(options) => options.with(|options| f(options)),
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static, L: Into<Oco<'static, str>>> FromIterator<(T, L)> for ChoiceOptions<T> {
+    fn from_iter<I: IntoIterator<Item = (T, L)>>(iter: I) -> Self {
+        Self::Static(iter.into_iter().map(|(value, label)| (value, label.into())).collect())
+    }
+}
+
+impl<T: Send + Sync + 'static> From<Vec<(T, Oco<'static, str>)>> for ChoiceOptions<T> {
+    fn from(options: Vec<(T, Oco<'static, str>)>) -> Self {
+        Self::Static(options)
+    }
+}
+
+impl<T: Send + Sync + 'static> From<Signal<Vec<(T, Oco<'static, str>)>>> for ChoiceOptions<T> {
+    fn from(options: Signal<Vec<(T, Oco<'static, str>)>>) -> Self {
+        Self::Signal(options)
+    }
+}
+
+impl<T: Send + Sync + 'static> ChoiceConfig<T> {
+    pub fn new(options: impl Into<ChoiceOptions<T>>) -> Self {
+        Self {
+            options: options.into(),
+            placeholder: None,
+        }
+    }
+
+    pub fn with_placeholder(mut self, placeholder: impl Into<Oco<'static, str>>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+}
+
+macro_rules! choice_impl {
+    ($($ty:ty),*$(,)?) => { $(
+        impl Choice for $ty {
+            fn to_choice(&self) -> String {
+                self.to_string()
+            }
+            fn from_choice(value: &str) -> Result<Self, FormError> {
+                value.parse().map_err(FormError::parse)
+            }
+        }
+    )* };
+}
+
+choice_impl!(String, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(feature = "uuid")]
+choice_impl!(::uuid::Uuid);
+
+#[cfg(feature = "ulid")]
+choice_impl!(::ulid::Ulid);
+
+macro_rules! choice_field_impl {
+    ($($el:ty),*$(,)?) => { $(
+        impl<T: Choice> FormField<$el> for T {
+            type Config = ChoiceConfig<T>;
+            type Signal = FormFieldSignal<String>;
+
+            fn default_signal(_: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                FormFieldSignal::new_with_default_value(initial.map(|x| x.to_choice()))
+            }
+            fn is_default_value(signal: &Self::Signal) -> bool {
+                signal.value.with_untracked(|value| value.is_empty())
+            }
+            fn into_signal(self, _: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                FormFieldSignal::new(self.to_choice(), initial.map(|x| x.to_choice()))
+            }
+            fn try_from_signal(signal: Self::Signal, _: &Self::Config) -> Result<Self, FormError> {
+                signal.value.with_untracked(|value| match value.is_empty() {
+                    true => Err(FormError::parse("no option is selected")),
+                    false => T::from_choice(value),
+                })
+            }
+            fn recurse(signal: &Self::Signal) {
+                signal.value.with_untracked(|_| {})
+            }
+            fn reset_initial_value(signal: &Self::Signal) {
+                signal.value.with_untracked(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
+            }
+            fn has_changed(signal: &Self::Signal) -> bool {
+                signal.has_changed_tracked()
+            }
+            fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
+                signal.error.with(|error| f(error.as_ref()))
+            }
+            fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+                signal.error.set(error)
+            }
+            fn is_pending(signal: &Self::Signal) -> bool {
+                signal.pending.get()
+            }
+            fn set_pending(signal: &Self::Signal, pending: bool) {
+                signal.pending.set(pending)
+            }
+            fn is_touched(signal: &Self::Signal) -> bool {
+                signal.touched.get()
+            }
+            fn touch(signal: &Self::Signal) {
+                signal.touched.set(true)
+            }
+        }
+    )* };
+}
+
+choice_field_impl!(HtmlElement<Select, (), ()>, RadioGroup);
+
+#[cfg(feature = "thaw")]
+impl<T: Choice> FormComponent<HtmlElement<Select, (), ()>> for T {
+    fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+        let class = props.class_signal();
+        let events = FieldEvents::<Self, HtmlElement<Select, (), ()>>::new(&props);
+        let value = props.signal.value;
+        let options = props.config.options.clone();
+        let placeholder = match props.is_optional {
+            true => Some(props.config.placeholder.clone().unwrap_or_default()),
+            false => props.config.placeholder.clone(),
+        };
+        view! {
+            <thaw::Select
+                class={class.get().map(|s| s.to_string())}
+                id={props.id.map(|s| s.to_string()).or_else(|| props.name.clone().map(|s| s.to_string()))}
+                name={props.name.map(|s| s.to_string())}
+                on:input=move |ev| {
+                    value.set(event_target_value(&ev));
+                    events.handle(ValidateOn::Input)
+                }
+                on:change=move |_| events.handle(ValidateOn::Change)
+                on:focusout=move |_| events.handle(ValidateOn::Blur)
+                value=value
+            >
+                {placeholder.map(|placeholder| view! { <option value="">{placeholder.to_string()}</option> })}
+                {move || options.with(|options| options.iter().map(|(option, label)| {
+                    view! { <option value=option.to_choice()>{label.to_string()}</option> }
+                }).collect_view())}
+            </thaw::Select>
+        }
+    }
+}
+
+#[cfg(not(feature = "thaw"))]
+impl<T: Choice> FormComponent<HtmlElement<Select, (), ()>> for T {
+    fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+        let class = props.class_signal();
+        let events = FieldEvents::<Self, HtmlElement<Select, (), ()>>::new(&props);
+        let value = props.signal.value;
+        let options = props.config.options.clone();
+        let placeholder = match props.is_optional {
+            true => Some(props.config.placeholder.clone().unwrap_or_default()),
+            false => props.config.placeholder.clone(),
+        };
+        view! {
+            <select
+                class={class}
+                id={props.id.or_else(|| props.name.clone())}
+                name={props.name}
+                required=props.input_attrs.required
+                autocomplete=props.input_attrs.autocomplete
+                on:input=move |ev| {
+                    value.set(event_target_value(&ev));
+                    events.handle(ValidateOn::Input)
+                }
+                on:change=move |_| events.handle(ValidateOn::Change)
+                on:focusout=move |_| events.handle(ValidateOn::Blur)
+                prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                prop:value=value
+                style={props.style}
+            >
+                {placeholder.map(|placeholder| view! {
+                    <option value="" selected=move || value.with(|value| value.is_empty())>{placeholder.to_string()}</option>
+                })}
+                {move || options.with(|options| options.iter().map(|(option, label)| {
+                    let option = option.to_choice();
+                    view! {
+                        <option value=option.clone() selected=move || value.with(|value| *value == option)>
+                            {label.to_string()}
+                        </option>
+                    }
+                }).collect_view())}
+            </select>
+        }
+    }
+}
+
+#[cfg(feature = "thaw")]
+impl<T: Choice> FormComponent<RadioGroup> for T {
+    fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+        let class = props.class_signal();
+        let events = FieldEvents::<Self, RadioGroup>::new(&props);
+        let value = props.signal.value;
+        let options = props.config.options.clone();
+        view! {
+            <thaw::RadioGroup
+                class={class.get().map(|s| s.to_string())}
+                id={props.id.map(|s| s.to_string()).or_else(|| props.name.clone().map(|s| s.to_string()))}
+                name={props.name.map(|s| s.to_string())}
+                on:change=move |_| {
+                    events.handle(ValidateOn::Input);
+                    events.handle(ValidateOn::Change)
+                }
+                on:focusout=move |_| events.handle(ValidateOn::Blur)
+                value=value
+            >
+                {move || options.with(|options| options.iter().map(|(option, label)| {
+                    view! { <thaw::Radio value=option.to_choice() label=label.to_string() /> }
+                }).collect_view())}
+            </thaw::RadioGroup>
+        }
+    }
+}
+
+#[cfg(not(feature = "thaw"))]
+impl<T: Choice> FormComponent<RadioGroup> for T {
+    fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+        let class = props.class_signal();
+        let events = FieldEvents::<Self, RadioGroup>::new(&props);
+        let value = props.signal.value;
+        let options = props.config.options.clone();
+        let name = props.name.clone();
+        let required = props.input_attrs.required;
+        view! {
+            <div
+                role="radiogroup"
+                class={class}
+                id={props.id.or(props.name)}
+                prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                style={props.style}
+            >
+                {move || options.with(|options| options.iter().map(|(option, label)| {
+                    let option = option.to_choice();
+                    view! {
+                        <label>
+                            <input
+                                type="radio"
+                                name={name.clone()}
+                                value=option.clone()
+                                required=required
+                                on:input=move |ev| {
+                                    value.set(event_target_value(&ev));
+                                    events.handle(ValidateOn::Input)
+                                }
+                                on:change=move |_| events.handle(ValidateOn::Change)
+                                on:focusout=move |_| events.handle(ValidateOn::Blur)
+                                prop:checked=move || value.with(|value| *value == option)
+                            />
+                            {label.to_string()}
+                        </label>
+                    }
+                }).collect_view())}
+            </div>
+        }
+    }
+}
@@ -17,6 +17,9 @@ pub struct VecConfig<Config: Default> {
     /// class to be placed on container element which wraps both the label and the item
     #[builder(setter(strip_option))]
     pub item_container_class: Option<Oco<'static, str>>,
+    /// class appended to an item's container element when the item was added or any of its values changed
+    #[builder(setter(strip_option))]
+    pub item_changed_class: Option<Oco<'static, str>>,
     /// custom class to be passed into each item's FormField props
     #[builder(setter(strip_option))]
     pub item_class: Option<Oco<'static, str>>,
@@ -130,13 +133,13 @@ impl<T: DefaultHtmlElement> DefaultHtmlElement for Vec<T> {
 
 #[derive(Clone, Copy, Debug)]
 pub struct VecSignalItem<Signal: std::clone::Clone> {
-    id: usize,
-    signal: Signal,
+    pub(crate) id: usize,
+    pub(crate) signal: Signal,
 }
 
 impl<T, El> FormField<Vec<El>> for Vec<T>
 where
-    T: Clone + FormField<El>,
+    T: FormField<El>,
     <T as FormField<El>>::Signal: Clone + Send + Sync + std::fmt::Debug,
 {
     type Config = VecConfig<<T as FormField<El>>::Config>;
@@ -167,7 +170,7 @@ where
             .map(|x| x.into_iter().map(Some).collect::<Vec<_>>())
             .unwrap_or_default();
         if initial.len() < self.len() {
-            initial.append(&mut vec![None; self.len() - initial.len()]);
+            initial.extend((initial.len()..self.len()).map(|_| None));
         }
         let value = self
             .into_iter()
@@ -188,29 +191,97 @@ where
     }
     fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
         signal.with_untracked(|value| {
-            value
-                .iter()
-                .map(|(_, item)| T::try_from_signal(item.signal.clone(), &config.item))
-                .collect()
+            let mut errors = FormErrors::new();
+            let items = value
+                .values()
+                .enumerate()
+                .filter_map(|(i, item)| {
+                    errors.record(T::try_from_signal(item.signal.clone(), &config.item).map_err(|err| err.at_index(i)))
+                })
+                .collect();
+            errors.into_result().map(|_| items)
         })
     }
     fn recurse(signal: &Self::Signal) {
         signal.with_untracked(|sig| sig.iter().for_each(|(_, sig)| T::recurse(&sig.signal)))
     }
     fn reset_initial_value(signal: &Self::Signal) {
-        signal.value.update(|value| {
+        signal.value.with_untracked(|value| {
             value.iter().for_each(|(_, item)| T::reset_initial_value(&item.signal));
+            signal.initial.update(|initial| *initial = Some(value.clone()));
         });
     }
-    fn with_error<O>(_: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
-        f(None)
+    fn has_changed(signal: &Self::Signal) -> bool {
+        signal.value.with(|value| {
+            let has_changed_keys = signal.initial.with(|initial| match initial {
+                Some(initial) => value.len() != initial.len() || value.keys().zip(initial.keys()).any(|(a, b)| a != b),
+                None => !value.is_empty(),
+            });
+            has_changed_keys || value.values().any(|item| T::has_changed(&item.signal))
+        })
+    }
+    fn validate(signal: &Self::Signal, config: &Self::Config) -> Result<(), FormError> {
+        signal.value.with_untracked(|value| {
+            let mut errors = FormErrors::new();
+            for (i, item) in value.values().enumerate() {
+                errors.record(T::validate(&item.signal, &config.item).map_err(|err| err.at_index(i)));
+            }
+            errors.into_result()
+        })
+    }
+    fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
+        signal.error.with(|error| f(error.as_ref()))
+    }
+    fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+        signal.error.set(error)
+    }
+    fn set_error_at(signal: &Self::Signal, path: &[PathSegment], error: FormError) -> Result<(), FormError> {
+        match path.split_first() {
+            None => {
+                Self::set_error(signal, Some(error));
+                Ok(())
+            }
+            Some((PathSegment::Index(i), path)) => signal.value.with_untracked(|value| match value.values().nth(*i) {
+                Some(item) => T::set_error_at(&item.signal, path, error),
+                None => Err(error),
+            }),
+            Some((PathSegment::Field(_), _)) => Err(error),
+        }
+    }
+    fn collect_errors(signal: &Self::Signal, errors: &mut FormErrors) {
+        Self::with_error(signal, |error| {
+            if let Some(error) = error {
+                errors.push(error.clone());
+            }
+        });
+        signal.value.with(|value| {
+            for (i, item) in value.values().enumerate() {
+                errors.extend(T::errors(&item.signal).into_iter().map(|err| err.at_index(i)));
+            }
+        })
+    }
+    fn is_pending(signal: &Self::Signal) -> bool {
+        signal.pending.get() || signal.value.with(|value| value.values().any(|item| T::is_pending(&item.signal)))
+    }
+    fn set_pending(signal: &Self::Signal, pending: bool) {
+        signal.pending.set(pending)
+    }
+    fn is_touched(signal: &Self::Signal) -> bool {
+        signal.touched.get() || signal.value.with(|value| value.values().any(|item| T::is_touched(&item.signal)))
+    }
+    fn touch(signal: &Self::Signal) {
+        signal.touched.set(true);
+        signal.value.with_untracked(|value| value.values().for_each(|item| T::touch(&item.signal)));
+    }
+    fn redact(&mut self) {
+        self.iter_mut().for_each(T::redact)
     }
 }
 
-impl<T, El, S> FormComponent<Vec<El>> for Vec<T>
+impl<T, El> FormComponent<Vec<El>> for Vec<T>
 where
-    T: Clone + FormComponent<El, Signal = FormFieldSignal<S>>,
-    S: Clone + Send + Sync + Eq + 'static + std::fmt::Debug,
+    T: FormComponent<El>,
+    <T as FormField<El>>::Signal: Send + Sync + std::fmt::Debug,
     <T as FormField<El>>::Config: std::fmt::Debug,
 {
     fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
@@ -254,6 +325,7 @@ where
         let VecConfig {
             item: item_config,
             item_container_class,
+            item_changed_class,
             item_class,
             item_label,
             item_style,
@@ -263,6 +335,7 @@ where
         } = props.config;
 
         let item_config_clone = item_config.clone();
+        let item_on_change = props.on_change.clone();
         view! {
             <div id={props.id} class={props.class} style={props.style}>
                 <For
@@ -271,12 +344,25 @@ where
                     children=move |(index, (key, item))| {
                         let id = || index.to_string();
 
+                        // items not present in the initial value count as changed
+                        let item_changed = {
+                            let item_signal = item.signal.clone();
+                            move || {
+                                T::has_changed(&item_signal)
+                                    || props.signal.initial.with(|initial| {
+                                        initial.as_ref().map(|initial| !initial.contains_key(&key)).unwrap_or(true)
+                                    })
+                            }
+                        };
+
                         let item_props = RenderProps::builder()
                             .id(Oco::Owned(id()))
                             .name(crate::format_form_name(props.name.as_ref(), id()))
                             .class(item_class.clone())
                             .style(item_style.clone())
                             .field_changed_class(props.field_changed_class.clone())
+                            .on_change(item_on_change.clone())
+                            .validate_on(props.validate_on)
                             .signal(item.signal)
                             .config(item_config.clone())
                             .build();
@@ -284,8 +370,11 @@ where
                         VecConfig::<<T as FormField<El>>::Config>::wrap(
                             &size,
                             item_container_class.clone(),
+                            item_changed_class.clone(),
+                            item_changed,
                             item_label.as_ref(),
                             &remove,
+                            item_on_change.clone(),
                             props.signal,
                             key,
                             Oco::Owned(id()),
@@ -309,6 +398,9 @@ where
                                 items.insert(id, VecSignalItem { id, signal: T::default_signal(&item_config_clone, None) });
                                 next_id.update(|x| *x = id + 1);
                             });
+                            if let Some(on_change) = props.on_change.as_ref() {
+                                on_change.call();
+                            }
                         }
                     };
 
@@ -375,11 +467,14 @@ static ASCII_UPPER: [char; 26] = [
 
 impl<Config: Default> VecConfig<Config> {
     #[allow(clippy::too_many_arguments)]
-    fn wrap<Signal: std::fmt::Debug + std::clone::Clone + Send + Sync + 'static>(
+    pub(crate) fn wrap<Signal: std::fmt::Debug + std::clone::Clone + Send + Sync + 'static>(
         size: &VecConfigSize,
         item_container_class: Option<Oco<'static, str>>,
+        item_changed_class: Option<Oco<'static, str>>,
+        item_changed: impl Fn() -> bool + Send + Sync + 'static,
         item_label: Option<&VecItemLabel>,
         remove_adornment: &Adornment,
+        on_change: Option<FieldCallback>,
         signal: FormFieldSignal<IndexMap<usize, VecSignalItem<Signal>>>,
         key: usize,
         id: Oco<'static, str>,
@@ -399,6 +494,9 @@ impl<Config: Default> VecConfig<Config> {
                 signal.update(|items| {
                     items.shift_remove(&key);
                 });
+                if let Some(on_change) = on_change.as_ref() {
+                    on_change.call();
+                }
             }
         };
 
@@ -432,8 +530,14 @@ impl<Config: Default> VecConfig<Config> {
             }
         };
 
+        let container_class = move || match (item_container_class.clone(), item_changed_class.clone()) {
+            (Some(class), Some(changed_class)) if item_changed() => Some(Oco::Owned(format!("{class} {changed_class}"))),
+            (None, Some(changed_class)) if item_changed() => Some(changed_class),
+            (class, _) => class,
+        };
+
         view! {
-            <div class={item_container_class} style="display: flex; flex-direction: row; align-items: center; margin-bottom: 0.5rem">
+            <div class={container_class} style="display: flex; flex-direction: row; align-items: center; margin-bottom: 0.5rem">
                 {match item_label {
                     Some(item_label) => item_label.wrap_label(key, id, item, signal),
                     None => item.into_any(),
@@ -477,7 +581,7 @@ impl VecItemLabel {
 }
 
 impl VecConfigSize {
-    fn split(&self) -> (Option<usize>, Option<usize>) {
+    pub(crate) fn split(&self) -> (Option<usize>, Option<usize>) {
         match *self {
             VecConfigSize::Bounded { min, max } => (min, max),
             VecConfigSize::Const(num_items) => (Some(num_items), Some(num_items)),
@@ -487,7 +591,7 @@ impl VecConfigSize {
 }
 
 impl VecItemLabelNotation {
-    fn render(&self, index: usize) -> String {
+    pub(crate) fn render(&self, index: usize) -> String {
         let display_index = index + 1;
         let ascii_set = match self {
             Self::CapitalLetter => &ASCII_UPPER,
@@ -506,7 +610,7 @@ impl VecItemLabelNotation {
 }
 
 impl VecItemLabelPunctuation {
-    fn render(&self) -> &'static str {
+    pub(crate) fn render(&self) -> &'static str {
         match self {
             Self::Parenthesis => ")",
             Self::Period => ".",
@@ -0,0 +1,378 @@
+use crate::*;
+use ::leptos::html::*;
+use ::leptos::prelude::*;
+use ::std::time::Duration;
+use ::wasm_bindgen::JsValue;
+
+/// Configuration for [`Duration`] fields.
+#[derive(Clone, Debug, Default, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+pub struct DurationConfig {
+    /// how the duration is entered
+    pub format: DurationFormat,
+    /// shortest accepted duration
+    #[builder(setter(strip_option))]
+    pub min: Option<Duration>,
+    /// longest accepted duration
+    #[builder(setter(strip_option))]
+    pub max: Option<Duration>,
+}
+
+/// How a [`Duration`] is entered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DurationFormat {
+    /// A number of units next to a selector of the unit, the value is initially shown in the given unit.
+    Units(DurationUnit),
+    /// An `HH:MM:SS` text input, optionally followed by milliseconds (e.g. `01:30:00.250`).
+    Clock,
+}
+
+/// A unit a [`Duration`] can be entered in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DurationUnit {
+    Milliseconds,
+    #[default]
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+}
+
+/// The text of a duration input and the unit it is entered in (unused by [`DurationFormat::Clock`]).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DurationInput {
+    pub amount: String,
+    pub unit: DurationUnit,
+}
+
+impl Default for DurationFormat {
+    fn default() -> Self {
+        Self::Units(Default::default())
+    }
+}
+
+impl DurationUnit {
+    pub const ALL: [Self; 5] = [
+        Self::Milliseconds,
+        Self::Seconds,
+        Self::Minutes,
+        Self::Hours,
+        Self::Days,
+    ];
+
+    pub fn millis(&self) -> u128 {
+        match self {
+            Self::Milliseconds => 1,
+            Self::Seconds => 1_000,
+            Self::Minutes => 60_000,
+            Self::Hours => 3_600_000,
+            Self::Days => 86_400_000,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Milliseconds => "ms",
+            Self::Seconds => "s",
+            Self::Minutes => "min",
+            Self::Hours => "h",
+            Self::Days => "days",
+        }
+    }
+}
+
+impl DurationFormat {
+    pub fn format(&self, duration: Duration) -> DurationInput {
+        match *self {
+            Self::Units(unit) => {
+                let millis = duration.as_millis();
+                let amount = match millis % unit.millis() {
+                    0 => (millis / unit.millis()).to_string(),
+                    _ => (duration.as_secs_f64() * 1000. / unit.millis() as f64).to_string(),
+                };
+                DurationInput { amount, unit }
+            }
+            Self::Clock => {
+                let secs = duration.as_secs();
+                let mut amount = format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60);
+                if duration.subsec_millis() != 0 {
+                    amount.push_str(&format!(".{:03}", duration.subsec_millis()));
+                }
+                DurationInput {
+                    amount,
+                    unit: Default::default(),
+                }
+            }
+        }
+    }
+
+    pub fn parse(&self, input: &DurationInput) -> Result<Duration, FormError> {
+        let amount = input.amount.trim();
+        match self {
+            Self::Units(_) => {
+                let amount = amount.parse::<f64>().map_err(FormError::parse)?;
+                if amount < 0. {
+                    return Err(FormError::validation("must not be negative")
+                        .with_code("range")
+                        .with_param("min", 0));
+                }
+                Duration::try_from_secs_f64(amount * input.unit.millis() as f64 / 1000.).map_err(FormError::parse)
+            }
+            Self::Clock => {
+                let invalid = || FormError::parse("expected a duration formatted as HH:MM:SS");
+                let (hours, rest) = amount.split_once(':').ok_or_else(invalid)?;
+                let (minutes, seconds) = rest.split_once(':').ok_or_else(invalid)?;
+                let hours = hours.parse::<u64>().map_err(|_| invalid())?;
+                let minutes = minutes.parse::<u64>().ok().filter(|x| *x < 60).ok_or_else(invalid)?;
+                let seconds = seconds
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|x| (0. ..60.).contains(x))
+                    .ok_or_else(invalid)?;
+                let overflow = || FormError::parse("duration is too long");
+                let secs = hours
+                    .checked_mul(3600)
+                    .and_then(|secs| secs.checked_add(minutes * 60))
+                    .ok_or_else(overflow)?;
+                Duration::from_secs(secs)
+                    .checked_add(Duration::from_secs_f64(seconds))
+                    .ok_or_else(overflow)
+            }
+        }
+    }
+}
+
+impl DefaultHtmlElement for Duration {
+    type El = HtmlElement<Input, (), ()>;
+}
+
+impl FormField<HtmlElement<Input, (), ()>> for Duration {
+    type Config = DurationConfig;
+    type Signal = FormFieldSignal<DurationInput>;
+
+    fn default_signal(config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+        let signal = FormFieldSignal::new_with_default_value(initial.map(|x| config.format.format(x)));
+        if let DurationFormat::Units(unit) = config.format {
+            signal.value.update_untracked(|value| value.unit = unit);
+        }
+        signal
+    }
+    fn is_default_value(signal: &Self::Signal) -> bool {
+        signal.value.with_untracked(|value| value.amount.is_empty())
+    }
+    fn into_signal(self, config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+        FormFieldSignal::new(
+            config.format.format(self),
+            initial.map(|initial| config.format.format(initial)),
+        )
+    }
+    fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+        let duration = signal.value.with_untracked(|value| config.format.parse(value))?;
+        if let Some(min) = config.min.filter(|min| duration < *min) {
+            let min = config.format.format(min);
+            return Err(
+                FormError::validation(format!("must be at least {} {}", min.amount, min.unit.label()))
+                    .with_code("range")
+                    .with_param("min", min.amount),
+            );
+        }
+        if let Some(max) = config.max.filter(|max| duration > *max) {
+            let max = config.format.format(max);
+            return Err(
+                FormError::validation(format!("must be at most {} {}", max.amount, max.unit.label()))
+                    .with_code("range")
+                    .with_param("max", max.amount),
+            );
+        }
+        Ok(duration)
+    }
+    fn recurse(signal: &Self::Signal) {
+        signal.value.with_untracked(|_| {})
+    }
+    fn reset_initial_value(signal: &Self::Signal) {
+        signal
+            .value
+            .with_untracked(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
+    }
+    fn has_changed(signal: &Self::Signal) -> bool {
+        signal.has_changed_tracked()
+    }
+    fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
+        signal.error.with(|error| f(error.as_ref()))
+    }
+    fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+        signal.error.set(error)
+    }
+    fn is_pending(signal: &Self::Signal) -> bool {
+        signal.pending.get()
+    }
+    fn set_pending(signal: &Self::Signal, pending: bool) {
+        signal.pending.set(pending)
+    }
+    fn is_touched(signal: &Self::Signal) -> bool {
+        signal.touched.get()
+    }
+    fn touch(signal: &Self::Signal) {
+        signal.touched.set(true)
+    }
+}
+
+impl FormComponent<HtmlElement<Input, (), ()>> for Duration {
+    fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+        let class = props.class_signal();
+        let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
+        let value = props.signal.value;
+        let amount = move || value.with(|value| value.amount.clone());
+        let id = props.id.or_else(|| props.name.clone());
+
+        match props.config.format {
+            DurationFormat::Units(_) => view! {
+                <div style="display: flex; flex-direction: row; gap: 0.5rem;">
+                    <input
+                        type="number"
+                        class={class}
+                        id={id}
+                        min="0"
+                        name={props.name.clone()}
+                        required=props.input_attrs.required
+                        step=props.input_attrs.step.or(Some(Oco::Borrowed("any")))
+                        inputmode=props.input_attrs.input_mode.or(Some(Oco::Borrowed("decimal")))
+                        on:input=move |ev| {
+                            value.update(|value| value.amount = event_target_value(&ev));
+                            events.handle(ValidateOn::Input)
+                        }
+                        on:change=move |_| events.handle(ValidateOn::Change)
+                        on:focusout=move |_| events.handle(ValidateOn::Blur)
+                        prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                        prop:value=amount
+                        style={props.style}
+                        value=amount
+                    />
+                    <select
+                        name={props.name.map(|name| crate::format_form_name(Some(&name), "unit"))}
+                        on:input=move |ev| {
+                            let label = event_target_value(&ev);
+                            if let Some(unit) = DurationUnit::ALL.into_iter().find(|unit| unit.label() == label) {
+                                value.update(|value| value.unit = unit);
+                            }
+                            events.handle(ValidateOn::Input)
+                        }
+                        on:change=move |_| events.handle(ValidateOn::Change)
+                        on:focusout=move |_| events.handle(ValidateOn::Blur)
+                        prop:value=move || value.with(|value| value.unit.label())
+                    >
+                        {DurationUnit::ALL.into_iter().map(|unit| view! {
+                            <option value=unit.label() selected=move || value.with(|value| value.unit == unit)>
+                                {unit.label()}
+                            </option>
+                        }).collect_view()}
+                    </select>
+                </div>
+            }
+            .into_any(),
+            DurationFormat::Clock => view! {
+                <input
+                    type="text"
+                    class={class}
+                    id={id}
+                    name={props.name}
+                    placeholder="HH:MM:SS"
+                    required=props.input_attrs.required
+                    pattern=props.input_attrs.pattern.or(Some(Oco::Borrowed(r"\d+:[0-5]\d:[0-5]\d(\.\d{1,3})?")))
+                    inputmode=props.input_attrs.input_mode
+                    autocomplete=props.input_attrs.autocomplete
+                    on:input=move |ev| {
+                        value.update(|value| value.amount = event_target_value(&ev));
+                        events.handle(ValidateOn::Input)
+                    }
+                    on:change=move |_| events.handle(ValidateOn::Change)
+                    on:focusout=move |_| events.handle(ValidateOn::Blur)
+                    prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                    prop:value=amount
+                    style={props.style}
+                    value=amount
+                />
+            }
+            .into_any(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn input(amount: &str, unit: DurationUnit) -> DurationInput {
+        DurationInput {
+            amount: amount.to_string(),
+            unit,
+        }
+    }
+
+    #[test]
+    fn units_are_formatted_in_the_configured_unit() {
+        let format = DurationFormat::Units(DurationUnit::Minutes);
+        assert_eq!(
+            input("2", DurationUnit::Minutes),
+            format.format(Duration::from_secs(120))
+        );
+        assert_eq!(
+            input("1.5", DurationUnit::Minutes),
+            format.format(Duration::from_secs(90))
+        );
+    }
+
+    #[test]
+    fn units_are_parsed_in_the_selected_unit() {
+        let format = DurationFormat::Units(DurationUnit::Seconds);
+        assert_eq!(
+            Duration::from_secs(5400),
+            format.parse(&input("1.5", DurationUnit::Hours)).unwrap()
+        );
+        assert_eq!(
+            Duration::from_millis(250),
+            format.parse(&input(" 250 ", DurationUnit::Milliseconds)).unwrap()
+        );
+        assert!(format
+            .parse(&input("-1", DurationUnit::Seconds))
+            .unwrap_err()
+            .is_validation());
+        assert!(format
+            .parse(&input("abc", DurationUnit::Seconds))
+            .unwrap_err()
+            .is_parse());
+        assert!(format
+            .parse(&input("1e300", DurationUnit::Days))
+            .unwrap_err()
+            .is_parse());
+    }
+
+    #[test]
+    fn clock_is_formatted_with_optional_milliseconds() {
+        let format = DurationFormat::Clock;
+        assert_eq!("01:30:05", format.format(Duration::from_secs(5405)).amount);
+        assert_eq!("00:00:01.250", format.format(Duration::from_millis(1250)).amount);
+        assert_eq!("100:00:00", format.format(Duration::from_secs(360_000)).amount);
+    }
+
+    #[test]
+    fn clock_is_parsed() {
+        let format = DurationFormat::Clock;
+        let parse = |amount: &str| format.parse(&input(amount, Default::default()));
+        assert_eq!(Duration::from_secs(5405), parse("01:30:05").unwrap());
+        assert_eq!(Duration::from_millis(1250), parse("0:00:01.25").unwrap());
+        assert!(parse("01:60:00").is_err());
+        assert!(parse("01:00:60").is_err());
+        assert!(parse("01:00").is_err());
+        assert!(parse("-1:00:00").is_err());
+    }
+
+    #[test]
+    fn overflowing_clock_durations_are_rejected() {
+        let format = DurationFormat::Clock;
+        let parse = |amount: &str| format.parse(&input(amount, Default::default()));
+        assert!(parse("6000000000000000:00:00").unwrap_err().is_parse());
+        assert!(parse(&format!("{}:00:00", u64::MAX / 3600)).is_ok());
+        assert!(parse(&format!("{}:59:59", u64::MAX / 3600)).unwrap_err().is_parse());
+    }
+}
@@ -0,0 +1,331 @@
+use crate::components::use_form_status;
+use crate::*;
+use ::leptos::html::*;
+use ::leptos::prelude::*;
+use ::send_wrapper::SendWrapper;
+use ::std::fmt;
+use ::wasm_bindgen::{JsValue, UnwrapThrowExt};
+use ::web_sys::{DataTransfer, File, FileList, Url};
+
+/// A file picked in a file input or dropped onto its drop zone.
+///
+/// Files only exist in the browser: a form holding them is submitted with the `multipart` component attribute
+/// which sends the files along with the other entries of the form as `multipart/form-data`.
+#[derive(Clone)]
+pub struct FileUpload(SendWrapper<File>);
+
+/// Any number of files picked in a file input or dropped onto its drop zone, see [`FileUpload`].
+#[derive(Clone, Debug, Default, Deref, DerefMut, From, IntoIterator, PartialEq)]
+pub struct FileUploads(pub Vec<FileUpload>);
+
+/// Configuration for [`FileUpload`] and [`FileUploads`] fields.
+#[derive(Clone, Debug, Default, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+pub struct FileConfig {
+    /// comma separated file extensions (e.g. `.pdf`) and mime types (e.g. `image/*`) which are accepted
+    #[builder(setter(strip_option))]
+    pub accept: Option<Oco<'static, str>>,
+    /// largest accepted file size in bytes
+    #[builder(setter(strip_option))]
+    pub max_size: Option<u64>,
+    /// most files accepted by a [`FileUploads`] field
+    #[builder(setter(strip_option))]
+    pub max_count: Option<usize>,
+    /// display a thumbnail of each selected image
+    pub previews: bool,
+}
+
+impl FileUpload {
+    pub fn new(file: File) -> Self {
+        Self(SendWrapper::new(file))
+    }
+
+    pub fn file(&self) -> &File {
+        &self.0
+    }
+
+    pub fn name(&self) -> String {
+        self.0.name()
+    }
+
+    /// The size of the file in bytes.
+    pub fn size(&self) -> u64 {
+        self.0.size() as u64
+    }
+
+    /// The mime type of the file as reported by the browser, empty if it is unknown.
+    pub fn mime_type(&self) -> String {
+        self.0.type_()
+    }
+
+    pub fn is_image(&self) -> bool {
+        self.mime_type().starts_with("image/")
+    }
+}
+
+impl fmt::Debug for FileUpload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileUpload")
+            .field("name", &self.name())
+            .field("size", &self.size())
+            .field("mime_type", &self.mime_type())
+            .finish()
+    }
+}
+
+/// Files are equal when they are the same browser file handle.
+impl PartialEq for FileUpload {
+    fn eq(&self, other: &Self) -> bool {
+        *self.0 == *other.0
+    }
+}
+
+impl From<File> for FileUpload {
+    fn from(file: File) -> Self {
+        Self::new(file)
+    }
+}
+
+impl FileConfig {
+    fn accepts(&self, file: &FileUpload) -> bool {
+        let Some(accept) = self.accept.as_ref() else {
+            return true;
+        };
+        let name = file.name().to_lowercase();
+        let mime_type = file.mime_type().to_lowercase();
+        accept
+            .split(',')
+            .map(|x| x.trim().to_lowercase())
+            .filter(|x| !x.is_empty())
+            .any(|x| match x.strip_suffix("/*") {
+                _ if x.starts_with('.') => name.ends_with(&x),
+                Some(prefix) => mime_type.split_once('/').is_some_and(|(ty, _)| ty == prefix),
+                None => mime_type == x,
+            })
+    }
+
+    fn check(&self, files: &[FileUpload]) -> Result<(), FormError> {
+        if let Some(max_count) = self.max_count.filter(|max_count| files.len() > *max_count) {
+            return Err(FormError::validation(format!("must contain at most {max_count} files"))
+                .with_code("max_count")
+                .with_param("max", max_count));
+        }
+        for file in files {
+            if !self.accepts(file) {
+                return Err(
+                    FormError::validation(format!("{} is not an accepted type of file", file.name()))
+                        .with_code("accept")
+                        .with_param("accept", self.accept.as_deref().unwrap_or_default()),
+                );
+            }
+            if let Some(max_size) = self.max_size.filter(|max_size| file.size() > *max_size) {
+                return Err(FormError::validation(format!(
+                    "{} must be at most {}",
+                    file.name(),
+                    format_size(max_size)
+                ))
+                .with_code("max_size")
+                .with_param("max", max_size));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn format_size(bytes: u64) -> String {
+    match bytes {
+        0..=999 => format!("{bytes} B"),
+        1_000..=999_999 => format!("{:.1} kB", bytes as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1} MB", bytes as f64 / 1e6),
+        _ => format!("{:.1} GB", bytes as f64 / 1e9),
+    }
+}
+
+fn file_list(files: FileList) -> impl Iterator<Item = FileUpload> {
+    (0..files.length())
+        .filter_map(move |i| files.get(i))
+        .map(FileUpload::new)
+}
+
+macro_rules! file_impl {
+    ($($ty:ty { multiple: $multiple:literal, try_from_files: |$files:ident| $try_from_files:expr, into_files: |$value:ident| $into_files:expr $(,)? }),* $(,)?) => { $(
+        impl DefaultHtmlElement for $ty {
+            type El = HtmlElement<Input, (), ()>;
+        }
+
+        impl FormField<HtmlElement<Input, (), ()>> for $ty {
+            type Config = FileConfig;
+            type Signal = FormFieldSignal<Vec<FileUpload>>;
+
+            fn default_signal(_: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                FormFieldSignal::new_with_default_value(initial.map(|$value| $into_files))
+            }
+            fn is_default_value(signal: &Self::Signal) -> bool {
+                signal.value.with_untracked(|value| value.is_empty())
+            }
+            fn into_signal(self, _: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                let into_files = |$value: Self| -> Vec<FileUpload> { $into_files };
+                FormFieldSignal::new(into_files(self), initial.map(into_files))
+            }
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                signal.value.with_untracked(|$files| {
+                    config.check($files)?;
+                    $try_from_files
+                })
+            }
+            fn recurse(signal: &Self::Signal) {
+                signal.value.with_untracked(|_| {})
+            }
+            fn reset_initial_value(signal: &Self::Signal) {
+                signal
+                    .value
+                    .with_untracked(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
+            }
+            fn has_changed(signal: &Self::Signal) -> bool {
+                signal.has_changed_tracked()
+            }
+            fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
+                signal.error.with(|error| f(error.as_ref()))
+            }
+            fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+                signal.error.set(error)
+            }
+            fn is_pending(signal: &Self::Signal) -> bool {
+                signal.pending.get()
+            }
+            fn set_pending(signal: &Self::Signal, pending: bool) {
+                signal.pending.set(pending)
+            }
+            fn is_touched(signal: &Self::Signal) -> bool {
+                signal.touched.get()
+            }
+            fn touch(signal: &Self::Signal) {
+                signal.touched.set(true)
+            }
+        }
+
+        impl FormComponent<HtmlElement<Input, (), ()>> for $ty {
+            fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+                let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
+                render_files(props, $multiple, move |event| events.handle(event))
+            }
+        }
+    )* };
+}
+
+file_impl!(
+    FileUpload {
+        multiple: false,
+        try_from_files: |files| files
+            .first()
+            .cloned()
+            .ok_or_else(|| FormError::validation("no file selected").with_code("required")),
+        into_files: |value| vec![value],
+    },
+    FileUploads {
+        multiple: true,
+        try_from_files: |files| Ok(FileUploads(files.clone())),
+        into_files: |value| value.0,
+    },
+);
+
+/// Renders a file input inside of a drop zone followed by the selected files.
+///
+/// The selected files are kept in sync with the files of the input so that they are
+/// included in the entries of the form element when it is submitted as `multipart/form-data`.
+fn render_files(
+    props: RenderProps<FormFieldSignal<Vec<FileUpload>>, FileConfig>,
+    multiple: bool,
+    handle: impl Fn(ValidateOn) + Copy + Send + Sync + 'static,
+) -> impl IntoView {
+    let class = props.class_signal();
+    let value = props.signal.value;
+    let input = NodeRef::<Input>::new();
+    let dragging = RwSignal::new(false);
+    let previews = props.config.previews;
+    let form_status = use_form_status();
+
+    Effect::new(move |_| {
+        let files = value.get();
+        let Some(input) = input.get() else { return };
+        let data_transfer = DataTransfer::new().unwrap_throw();
+        for file in &files {
+            data_transfer.items().add_with_file(file.file()).unwrap_throw();
+        }
+        input.set_files(data_transfer.files().as_ref());
+    });
+
+    let add = move |files: FileList| {
+        value.update(|value| match multiple {
+            true => value.extend(file_list(files)),
+            false => *value = file_list(files).take(1).collect(),
+        });
+        handle(ValidateOn::Change)
+    };
+
+    view! {
+        <div
+            data-dragging=move || dragging.get().then_some("")
+            on:dragover=move |ev| {
+                ev.prevent_default();
+                dragging.set(true);
+            }
+            on:dragleave=move |_| dragging.set(false)
+            on:drop=move |ev| {
+                ev.prevent_default();
+                dragging.set(false);
+                if let Some(files) = ev.data_transfer().and_then(|x| x.files()) {
+                    add(files);
+                }
+            }
+        >
+            <input
+                type="file"
+                class={class}
+                id={props.id.or_else(|| props.name.clone())}
+                name={props.name}
+                accept={props.config.accept.clone()}
+                multiple=multiple
+                required=props.input_attrs.required
+                node_ref=input
+                on:change=move |_| {
+                    if let Some(files) = input.get_untracked().and_then(|input| input.files()) {
+                        add(files);
+                    }
+                }
+                on:focusout=move |_| handle(ValidateOn::Blur)
+                prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                style={props.style}
+            />
+            <ul>
+                {move || value.get().into_iter().enumerate().map(|(i, file)| {
+                    let preview = (previews && file.is_image())
+                        .then(|| Url::create_object_url_with_blob(file.file()).ok())
+                        .flatten();
+                    if let Some(url) = preview.clone() {
+                        on_cleanup(move || Url::revoke_object_url(&url).unwrap_throw());
+                    }
+                    view! {
+                        <li>
+                            {preview.map(|src| view! { <img src=src alt=file.name() style="max-height: 4rem;" /> })}
+                            <span>{format!("{} ({})", file.name(), format_size(file.size()))}</span>
+                            <button
+                                type="button"
+                                on:click=move |_| {
+                                    value.update(|value| { value.remove(i); });
+                                    handle(ValidateOn::Change)
+                                }
+                            >
+                                "Remove"
+                            </button>
+                        </li>
+                    }
+                }).collect_view()}
+            </ul>
+            // server functions submit with `fetch`, which does not report upload progress, so the bar is indeterminate
+            {move || form_status
+                .filter(|status| status.submitting.get() && !value.with(|value| value.is_empty()))
+                .map(|_| view! { <progress /> })}
+        </div>
+    }
+}
@@ -0,0 +1,503 @@
+use super::config::collections::*;
+use crate::*;
+use ::indexmap::IndexMap;
+use ::leptos::prelude::*;
+use ::std::collections::{BTreeMap, HashMap};
+use ::std::fmt::Debug;
+use ::std::hash::Hash;
+
+/// Configuration for a map of FormFields, rendered as a list of key/value entries
+#[derive(Clone, Default, Derivative, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[derivative(Debug)]
+pub struct MapConfig<KeyConfig: Default, ValueConfig: Default> {
+    /// configuration passed to each entry's key
+    pub key: KeyConfig,
+    /// configuration passed to each entry's value
+    pub value: ValueConfig,
+    /// class to be placed on container element which wraps an entry's key and value
+    #[builder(setter(strip_option))]
+    pub entry_container_class: Option<Oco<'static, str>>,
+    /// class appended to an entry's container element when the entry was added or its key or value changed
+    #[builder(setter(strip_option))]
+    pub entry_changed_class: Option<Oco<'static, str>>,
+    /// custom class to be passed into each key's FormField props
+    #[builder(setter(strip_option))]
+    pub key_class: Option<Oco<'static, str>>,
+    /// custom style to be passed into each key's FormField props
+    #[builder(setter(strip_option))]
+    pub key_style: Option<Oco<'static, str>>,
+    /// custom class to be passed into each value's FormField props
+    #[builder(setter(strip_option))]
+    pub value_class: Option<Oco<'static, str>>,
+    /// custom style to be passed into each value's FormField props
+    #[builder(setter(strip_option))]
+    pub value_style: Option<Oco<'static, str>>,
+    /// map size control for this form
+    pub size: VecConfigSize,
+    /// configuration for an add button at the end of the listed entries
+    pub add: Adornment,
+    /// configuration for the remove buttons adorning each entry
+    pub remove: Adornment,
+}
+
+/// The signals of a map entry's key and value.
+#[derive(Clone, Copy, Debug)]
+pub struct MapEntrySignal<KeySignal, ValueSignal> {
+    pub key: KeySignal,
+    pub value: ValueSignal,
+}
+
+type MapSignal<KeySignal, ValueSignal> =
+    FormFieldSignal<IndexMap<usize, VecSignalItem<MapEntrySignal<KeySignal, ValueSignal>>>>;
+
+fn duplicate_key_error() -> FormError {
+    FormError::validation("duplicate key").with_code("duplicate_key")
+}
+
+macro_rules! map_impl {
+    ($($map:ident<K: $($bound:path),+>),*$(,)?) => { $(
+        impl<K: DefaultHtmlElement, V: DefaultHtmlElement> DefaultHtmlElement for $map<K, V> {
+            type El = $map<K::El, V::El>;
+        }
+
+        impl<K, V, KEl, VEl> FormField<$map<KEl, VEl>> for $map<K, V>
+        where
+            K: FormField<KEl> $(+ $bound)+,
+            V: FormField<VEl>,
+            <K as FormField<KEl>>::Signal: Clone + Send + Sync + Debug,
+            <V as FormField<VEl>>::Signal: Clone + Send + Sync + Debug,
+        {
+            type Config = MapConfig<<K as FormField<KEl>>::Config, <V as FormField<VEl>>::Config>;
+            type Signal = MapSignal<<K as FormField<KEl>>::Signal, <V as FormField<VEl>>::Signal>;
+
+            fn default_signal(config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                FormFieldSignal::new_with_default_value(initial.map(|x| {
+                    x.into_iter()
+                        .enumerate()
+                        .map(|(i, (key, value))| {
+                            (
+                                i,
+                                VecSignalItem {
+                                    id: i,
+                                    signal: MapEntrySignal {
+                                        key: K::default_signal(&config.key, Some(key)),
+                                        value: V::default_signal(&config.value, Some(value)),
+                                    },
+                                },
+                            )
+                        })
+                        .collect::<IndexMap<_, _>>()
+                }))
+            }
+            fn is_default_value(signal: &Self::Signal) -> bool {
+                signal.value.with_untracked(|value| value.is_empty())
+            }
+            fn into_signal(self, config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                let has_initial = initial.is_some();
+                // entries are matched with the initial entry of the same key
+                let mut initial = initial.map(|x| x.into_iter().collect::<Vec<_>>()).unwrap_or_default();
+                let value = self
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, (key, value))| {
+                        let (initial_key, initial_value) = initial
+                            .iter()
+                            .position(|(initial_key, _)| *initial_key == key)
+                            .map(|position| initial.swap_remove(position))
+                            .unzip();
+                        (
+                            i,
+                            VecSignalItem {
+                                id: i,
+                                signal: MapEntrySignal {
+                                    key: key.into_signal(&config.key, initial_key),
+                                    value: value.into_signal(&config.value, initial_value),
+                                },
+                            },
+                        )
+                    })
+                    .collect::<IndexMap<_, _>>();
+                let initial = has_initial.then(|| value.clone());
+                FormFieldSignal::new(value, initial)
+            }
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                signal.with_untracked(|value| {
+                    let mut errors = FormErrors::new();
+                    let mut map = $map::new();
+                    for (i, entry) in value.values().enumerate() {
+                        let key = errors.record(
+                            K::try_from_signal(entry.signal.key.clone(), &config.key)
+                                .map_err(|err| err.in_field("key").at_index(i)),
+                        );
+                        let value = errors.record(
+                            V::try_from_signal(entry.signal.value.clone(), &config.value)
+                                .map_err(|err| err.in_field("value").at_index(i)),
+                        );
+                        match key {
+                            Some(key) if map.contains_key(&key) => {
+                                errors.push(duplicate_key_error().in_field("key").at_index(i))
+                            }
+                            Some(key) => {
+                                if let Some(value) = value {
+                                    map.insert(key, value);
+                                }
+                            }
+                            None => {}
+                        }
+                    }
+                    errors.into_result().map(|_| map)
+                })
+            }
+            fn recurse(signal: &Self::Signal) {
+                signal.with_untracked(|sig| {
+                    sig.values().for_each(|entry| {
+                        K::recurse(&entry.signal.key);
+                        V::recurse(&entry.signal.value);
+                    })
+                })
+            }
+            fn reset_initial_value(signal: &Self::Signal) {
+                signal.value.with_untracked(|value| {
+                    value.values().for_each(|entry| {
+                        K::reset_initial_value(&entry.signal.key);
+                        V::reset_initial_value(&entry.signal.value);
+                    });
+                    signal.initial.update(|initial| *initial = Some(value.clone()));
+                });
+            }
+            fn has_changed(signal: &Self::Signal) -> bool {
+                signal.value.with(|value| {
+                    let has_changed_keys = signal.initial.with(|initial| match initial {
+                        Some(initial) => value.len() != initial.len() || value.keys().zip(initial.keys()).any(|(a, b)| a != b),
+                        None => !value.is_empty(),
+                    });
+                    has_changed_keys
+                        || value
+                            .values()
+                            .any(|entry| K::has_changed(&entry.signal.key) || V::has_changed(&entry.signal.value))
+                })
+            }
+            /// Validates every key and value, and displays an error on each key which is equal to the key of a previous entry.
+            fn validate(signal: &Self::Signal, config: &Self::Config) -> Result<(), FormError> {
+                signal.value.with_untracked(|value| {
+                    let mut errors = FormErrors::new();
+                    let mut keys = $map::new();
+                    for (i, entry) in value.values().enumerate() {
+                        errors.record(K::validate(&entry.signal.key, &config.key).map_err(|err| err.in_field("key").at_index(i)));
+                        errors.record(V::validate(&entry.signal.value, &config.value).map_err(|err| err.in_field("value").at_index(i)));
+                        if let Ok(key) = K::try_from_signal(entry.signal.key.clone(), &config.key) {
+                            if keys.contains_key(&key) {
+                                K::set_error(&entry.signal.key, Some(duplicate_key_error()));
+                                errors.push(duplicate_key_error().in_field("key").at_index(i));
+                            } else {
+                                keys.insert(key, ());
+                            }
+                        }
+                    }
+                    errors.into_result()
+                })
+            }
+            fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
+                signal.error.with(|error| f(error.as_ref()))
+            }
+            fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+                signal.error.set(error)
+            }
+            fn set_error_at(signal: &Self::Signal, path: &[PathSegment], error: FormError) -> Result<(), FormError> {
+                match path.split_first() {
+                    None => {
+                        Self::set_error(signal, Some(error));
+                        Ok(())
+                    }
+                    Some((PathSegment::Index(i), path)) => signal.value.with_untracked(|value| {
+                        match (value.values().nth(*i), path.split_first()) {
+                            (Some(entry), Some((PathSegment::Field(name), path))) if name == "key" => {
+                                K::set_error_at(&entry.signal.key, path, error)
+                            }
+                            (Some(entry), Some((PathSegment::Field(name), path))) if name == "value" => {
+                                V::set_error_at(&entry.signal.value, path, error)
+                            }
+                            _ => Err(error),
+                        }
+                    }),
+                    Some((PathSegment::Field(_), _)) => Err(error),
+                }
+            }
+            fn collect_errors(signal: &Self::Signal, errors: &mut FormErrors) {
+                Self::with_error(signal, |error| {
+                    if let Some(error) = error {
+                        errors.push(error.clone());
+                    }
+                });
+                signal.value.with(|value| {
+                    for (i, entry) in value.values().enumerate() {
+                        errors.extend(K::errors(&entry.signal.key).into_iter().map(|err| err.in_field("key").at_index(i)));
+                        errors.extend(V::errors(&entry.signal.value).into_iter().map(|err| err.in_field("value").at_index(i)));
+                    }
+                })
+            }
+            fn is_pending(signal: &Self::Signal) -> bool {
+                signal.pending.get()
+                    || signal.value.with(|value| {
+                        value
+                            .values()
+                            .any(|entry| K::is_pending(&entry.signal.key) || V::is_pending(&entry.signal.value))
+                    })
+            }
+            fn set_pending(signal: &Self::Signal, pending: bool) {
+                signal.pending.set(pending)
+            }
+            fn is_touched(signal: &Self::Signal) -> bool {
+                signal.touched.get()
+                    || signal.value.with(|value| {
+                        value
+                            .values()
+                            .any(|entry| K::is_touched(&entry.signal.key) || V::is_touched(&entry.signal.value))
+                    })
+            }
+            fn touch(signal: &Self::Signal) {
+                signal.touched.set(true);
+                signal.value.with_untracked(|value| {
+                    value.values().for_each(|entry| {
+                        K::touch(&entry.signal.key);
+                        V::touch(&entry.signal.value);
+                    })
+                });
+            }
+            fn redact(&mut self) {
+                // keys are immutable, only values can be redacted
+                self.values_mut().for_each(V::redact)
+            }
+        }
+
+        impl<K, V, KEl, VEl> FormComponent<$map<KEl, VEl>> for $map<K, V>
+        where
+            K: FormComponent<KEl> $(+ $bound)+,
+            V: FormComponent<VEl>,
+            <K as FormField<KEl>>::Signal: Send + Sync + Debug,
+            <V as FormField<VEl>>::Signal: Send + Sync + Debug,
+            <K as FormField<KEl>>::Config: Debug,
+            <V as FormField<VEl>>::Config: Debug,
+        {
+            fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+                render_map::<K, V, KEl, VEl>(props)
+            }
+        }
+    )* };
+}
+
+map_impl!(
+    HashMap<K: Eq, Hash>,
+    BTreeMap<K: Ord>,
+    IndexMap<K: Eq, Hash>,
+);
+
+fn render_map<K, V, KEl, VEl>(
+    props: RenderProps<
+        MapSignal<<K as FormField<KEl>>::Signal, <V as FormField<VEl>>::Signal>,
+        MapConfig<<K as FormField<KEl>>::Config, <V as FormField<VEl>>::Config>,
+    >,
+) -> impl IntoView
+where
+    K: FormComponent<KEl>,
+    V: FormComponent<VEl>,
+    <K as FormField<KEl>>::Signal: Send + Sync + Debug,
+    <V as FormField<VEl>>::Signal: Send + Sync + Debug,
+    <K as FormField<KEl>>::Config: Debug,
+    <V as FormField<VEl>>::Config: Debug,
+{
+    let (min_entries, max_entries) = props.config.size.split();
+
+    let next_id = RwSignal::new(props.signal.with_untracked(|entries| {
+        if entries.is_empty() {
+            1
+        } else {
+            entries[entries.len() - 1].id + 1
+        }
+    }));
+
+    let new_entry = {
+        let key_config = props.config.key.clone();
+        let value_config = props.config.value.clone();
+        move || {
+            let id = next_id.get_untracked();
+            next_id.update(|x| *x = id + 1);
+            (
+                id,
+                VecSignalItem {
+                    id,
+                    signal: MapEntrySignal {
+                        key: K::default_signal(&key_config, None),
+                        value: V::default_signal(&value_config, None),
+                    },
+                },
+            )
+        }
+    };
+
+    if min_entries.is_some() || max_entries.is_some() {
+        props.signal.update(|entries| {
+            if let Some(min_entries) = min_entries {
+                while entries.len() < min_entries {
+                    let (id, entry) = new_entry();
+                    entries.insert(id, entry);
+                }
+            }
+            if let Some(max_entries) = max_entries {
+                while max_entries < entries.len() {
+                    entries.pop();
+                }
+            }
+        });
+    }
+
+    let MapConfig {
+        key: key_config,
+        value: value_config,
+        entry_container_class,
+        entry_changed_class,
+        key_class,
+        key_style,
+        value_class,
+        value_style,
+        size,
+        add,
+        remove,
+    } = props.config;
+
+    let entry_on_change = props.on_change.clone();
+    view! {
+        <div id={props.id} class={props.class} style={props.style}>
+            <For
+                key=|(_, (key, _))| *key
+                each=move || props.signal.value.get().into_iter().enumerate()
+                children=move |(index, (key, entry))| {
+                    let id: Oco<'static, str> = Oco::Owned(index.to_string());
+                    let name = crate::format_form_name(props.name.as_ref(), index.to_string());
+
+                    // entries not present in the initial value count as changed
+                    let entry_changed = {
+                        let entry_signal = entry.signal.clone();
+                        move || {
+                            K::has_changed(&entry_signal.key)
+                                || V::has_changed(&entry_signal.value)
+                                || props.signal.initial.with(|initial| {
+                                    initial.as_ref().map(|initial| !initial.contains_key(&key)).unwrap_or(true)
+                                })
+                        }
+                    };
+
+                    let entry_error = {
+                        let entry_signal = entry.signal.clone();
+                        move || {
+                            K::with_error(&entry_signal.key, |error| error.map(|error| error.to_string()))
+                                .or_else(|| V::with_error(&entry_signal.value, |error| error.map(|error| error.to_string())))
+                                .map(|error| view! { <span style="color: red;">{error}</span> })
+                        }
+                    };
+
+                    let key_props = RenderProps::builder()
+                        .id(crate::format_form_id(Some(&id), "key"))
+                        .name(crate::format_form_name(Some(&name), "key"))
+                        .class(key_class.clone())
+                        .style(key_style.clone())
+                        .field_changed_class(props.field_changed_class.clone())
+                        .on_change(entry_on_change.clone())
+                        .validate_on(props.validate_on)
+                        .signal(entry.signal.key.clone())
+                        .config(key_config.clone())
+                        .build();
+
+                    let value_props = RenderProps::builder()
+                        .id(crate::format_form_id(Some(&id), "value"))
+                        .name(crate::format_form_name(Some(&name), "value"))
+                        .class(value_class.clone())
+                        .style(value_style.clone())
+                        .field_changed_class(props.field_changed_class.clone())
+                        .on_change(entry_on_change.clone())
+                        .validate_on(props.validate_on)
+                        .signal(entry.signal.value.clone())
+                        .config(value_config.clone())
+                        .build();
+
+                    let entry_view = view! {
+                        <div style="display: flex; flex-direction: column;">
+                            <div style="display: flex; flex-direction: row; gap: 0.5rem;">
+                                {<K as FormComponent<KEl>>::render(key_props)}
+                                {<V as FormComponent<VEl>>::render(value_props)}
+                            </div>
+                            {entry_error}
+                        </div>
+                    };
+
+                    VecConfig::<()>::wrap(
+                        &size,
+                        entry_container_class.clone(),
+                        entry_changed_class.clone(),
+                        entry_changed,
+                        None,
+                        &remove,
+                        entry_on_change.clone(),
+                        props.signal,
+                        key,
+                        id,
+                        entry_view,
+                    ).into_any()
+                }
+            />
+            {
+                let num_entries_is_max = move || {
+                    let num_entries = props.signal.with_untracked(|entries| entries.len());
+                    num_entries >= max_entries.unwrap_or(usize::MAX)
+                };
+
+                let cursor = move || if num_entries_is_max() { None } else { Some("pointer") };
+                let opacity = move || if num_entries_is_max() { Some("0.5") } else { None };
+
+                let on_add = move |_| {
+                    if !num_entries_is_max() {
+                        props.signal.update(|entries| {
+                            let (id, entry) = new_entry();
+                            entries.insert(id, entry);
+                        });
+                        if let Some(on_change) = props.on_change.as_ref() {
+                            on_change.call();
+                        }
+                    }
+                };
+
+                match (&size, &add) {
+                    (VecConfigSize::Const(_), _)|(_, Adornment::None) => view!{}.into_any(),
+                    (_, Adornment::Component(component)) => component(::std::sync::Arc::new(on_add), ::std::sync::Arc::new(opacity)).into_any(),
+                    (_, Adornment::Default) => view! {
+                        <input
+                            type="button"
+                            on:click=on_add
+                            style:cursor=cursor
+                            style:margin-top="0.5 rem"
+                            style:opacity=opacity
+                            value="Add"
+                        />
+                    }
+                    .into_any(),
+                    (_, Adornment::Spec(adornment_spec)) => {
+                        let style = (adornment_spec.class.is_none() && adornment_spec.style.is_none()).then_some("margin-top: 0.5rem;");
+                        view! {
+                            <input
+                                type="button"
+                                class={adornment_spec.class.clone()}
+                                style:cursor=cursor
+                                on:click=on_add
+                                style:opacity=opacity
+                                style=style
+                                value={adornment_spec.text.clone().unwrap_or(Oco::Borrowed("Add"))}
+                            />
+                        }
+                        .into_any()
+                    }
+                }
+            }
+        </div>
+    }
+}
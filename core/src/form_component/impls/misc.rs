@@ -41,8 +41,26 @@ mod uuid {
                 .value
                 .with_untracked(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
         }
+        fn has_changed(signal: &Self::Signal) -> bool {
+            signal.has_changed_tracked()
+        }
         fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
-            signal.error.with_untracked(|error| f(error.as_ref()))
+            signal.error.with(|error| f(error.as_ref()))
+        }
+        fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+            signal.error.set(error)
+        }
+        fn is_pending(signal: &Self::Signal) -> bool {
+            signal.pending.get()
+        }
+        fn set_pending(signal: &Self::Signal, pending: bool) {
+            signal.pending.set(pending)
+        }
+        fn is_touched(signal: &Self::Signal) -> bool {
+            signal.touched.get()
+        }
+        fn touch(signal: &Self::Signal) {
+            signal.touched.set(true)
         }
     }
 
@@ -50,24 +68,19 @@ mod uuid {
     impl FormComponent<HtmlElement<Input, (), ()>> for ::uuid::Uuid {
         fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
             let class = props.class_signal();
+            let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
             view! {
                 <thaw::Input
                     input_type=thaw::InputType::Text
                     class={class.get().map(|s| s.to_string())}
                     id={props.id.map(|s| s.to_string()).or_else(|| props.name.clone().map(|s| s.to_string()))}
                     name={props.name.map(|s| s.to_string())}
-                    on:input=move |ev| props.signal.value.update(|value| *value = event_target_value(&ev))
-                    on:change=move |_| {
-                        if !props.is_optional || !<Self as FormField<HtmlElement<Input, (), ()>>>::is_default_value(&props.signal) {
-                            if let Err(form_error) = <Self as FormField<HtmlElement<Input, (), ()>>>::try_from_signal(props.signal, &props.config) {
-                                props.signal.error.update(|error| *error = Some(form_error));
-                            } else if props.signal.error.with_untracked(|error| error.is_some()) {
-                                props.signal.error.update(|error| *error = None);
-                            }
-                        } else {
-                            props.signal.error.update(|error| *error = None);
-                        }
+                    on:input=move |ev| {
+                        props.signal.value.update(|value| *value = event_target_value(&ev));
+                        events.handle(ValidateOn::Input)
                     }
+                    on:change=move |_| events.handle(ValidateOn::Change)
+                    on:focusout=move |_| events.handle(ValidateOn::Blur)
                     prop:class={move || class.with(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
                     prop:value={props.signal.value}
                     input_style={props.style.map(|s| s.to_string())}
@@ -81,24 +94,26 @@ mod uuid {
     impl FormComponent<HtmlElement<Input, (), ()>> for ::uuid::Uuid {
         fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
             let class = props.class_signal();
+            let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
             view! {
                 <input
                     type="text"
                     class={class}
                     id={props.id.or_else(|| props.name.clone())}
                     name={props.name}
-                    on:input=move |ev| props.signal.value.update(|value| *value = event_target_value(&ev))
-                    on:change=move |_| {
-                        if !props.is_optional || !<Self as FormField<HtmlElement<Input, (), ()>>>::is_default_value(&props.signal) {
-                            if let Err(form_error) = <Self as FormField<HtmlElement<Input, (), ()>>>::try_from_signal(props.signal, &props.config) {
-                                props.signal.error.update(|error| *error = Some(form_error));
-                            } else if props.signal.error.with_untracked(|error| error.is_some()) {
-                                props.signal.error.update(|error| *error = None);
-                            }
-                        } else {
-                            props.signal.error.update(|error| *error = None);
-                        }
+                    required=props.input_attrs.required
+                    minlength=props.input_attrs.min_length.map(|x| x.to_string())
+                    maxlength=props.input_attrs.max_length.map(|x| x.to_string())
+                    pattern=props.input_attrs.pattern
+                    step=props.input_attrs.step
+                    inputmode=props.input_attrs.input_mode
+                    autocomplete=props.input_attrs.autocomplete
+                    on:input=move |ev| {
+                        props.signal.value.update(|value| *value = event_target_value(&ev));
+                        events.handle(ValidateOn::Input)
                     }
+                    on:change=move |_| events.handle(ValidateOn::Change)
+                    on:focusout=move |_| events.handle(ValidateOn::Blur)
                     prop:class={move || class.with(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
                     prop:value={props.signal.value}
                     style={props.style}
@@ -145,32 +160,52 @@ mod ulid {
                 .value
                 .with(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
         }
+        fn has_changed(signal: &Self::Signal) -> bool {
+            signal.has_changed_tracked()
+        }
         fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
             signal.error.with(|error| f(error.as_ref()))
         }
+        fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+            signal.error.set(error)
+        }
+        fn is_pending(signal: &Self::Signal) -> bool {
+            signal.pending.get()
+        }
+        fn set_pending(signal: &Self::Signal, pending: bool) {
+            signal.pending.set(pending)
+        }
+        fn is_touched(signal: &Self::Signal) -> bool {
+            signal.touched.get()
+        }
+        fn touch(signal: &Self::Signal) {
+            signal.touched.set(true)
+        }
     }
 
     impl FormComponent<HtmlElement<Input>> for ::ulid::Ulid {
         fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
             let class = props.class_signal();
+            let events = FieldEvents::<Self, HtmlElement<Input>>::new(&props);
             view! {
                 <input
                     type="text"
                     class={class}
                     id={props.id.or_else(|| props.name.clone())}
                     name={props.name}
-                    on:input=move |ev| props.signal.value.update(|value| *value = event_target_value(&ev))
-                    on:change=move |_| {
-                        if !props.is_optional || !<Self as FormField<HtmlElement<Input>>>::is_default_value(&props.signal) {
-                            if let Err(form_error) = <Self as FormField<HtmlElement<Input>>>::try_from_signal(props.signal, &props.config) {
-                                props.signal.error.update(|error| *error = Some(form_error));
-                            } else if props.signal.error.with_untracked(|error| error.is_some()) {
-                                props.signal.error.update(|error| *error = None);
-                            }
-                        } else {
-                            props.signal.error.update(|error| *error = None);
-                        }
+                    required=props.input_attrs.required
+                    minlength=props.input_attrs.min_length.map(|x| x.to_string())
+                    maxlength=props.input_attrs.max_length.map(|x| x.to_string())
+                    pattern=props.input_attrs.pattern
+                    step=props.input_attrs.step
+                    inputmode=props.input_attrs.input_mode
+                    autocomplete=props.input_attrs.autocomplete
+                    on:input=move |ev| {
+                        props.signal.value.update(|value| *value = event_target_value(&ev));
+                        events.handle(ValidateOn::Input)
                     }
+                    on:change=move |_| events.handle(ValidateOn::Change)
+                    on:focusout=move |_| events.handle(ValidateOn::Blur)
                     prop:class={move || class.with(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
                     prop:value={props.signal.value}
                     style={props.style}
@@ -181,6 +216,510 @@ mod ulid {
     }
 }
 
+/// Implements FormField for a type entered as text and parsed with its `FromStr` impl,
+/// rendered as an input of the given `type` and `inputmode` (or as a `thaw::Input` of the given `thaw::InputType`).
+macro_rules! from_str_impl {
+    ($($ty:ty { type: $type:literal, inputmode: $input_mode:literal, thaw: $thaw_type:ident }),*$(,)?) => { $(
+        impl DefaultHtmlElement for $ty {
+            type El = HtmlElement<Input, (), ()>;
+        }
+
+        impl FormField<HtmlElement<Input, (), ()>> for $ty {
+            type Config = ();
+            type Signal = FormFieldSignal<String>;
+
+            fn default_signal(_: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                FormFieldSignal::new_with_default_value(initial.map(|x| x.to_string()))
+            }
+            fn is_default_value(signal: &Self::Signal) -> bool {
+                signal.value.with_untracked(|value| value.is_empty())
+            }
+            fn into_signal(self, _: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                FormFieldSignal::new(self.to_string(), initial.map(|x| x.to_string()))
+            }
+            fn try_from_signal(signal: Self::Signal, _: &Self::Config) -> Result<Self, FormError> {
+                use std::str::FromStr;
+                signal
+                    .value
+                    .with_untracked(|value| <$ty>::from_str(value.trim()))
+                    .map_err(FormError::parse)
+            }
+            fn recurse(signal: &Self::Signal) {
+                signal.value.with_untracked(|_| {})
+            }
+            fn reset_initial_value(signal: &Self::Signal) {
+                signal
+                    .value
+                    .with_untracked(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
+            }
+            fn has_changed(signal: &Self::Signal) -> bool {
+                signal.has_changed_tracked()
+            }
+            fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
+                signal.error.with(|error| f(error.as_ref()))
+            }
+            fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+                signal.error.set(error)
+            }
+            fn is_pending(signal: &Self::Signal) -> bool {
+                signal.pending.get()
+            }
+            fn set_pending(signal: &Self::Signal, pending: bool) {
+                signal.pending.set(pending)
+            }
+            fn is_touched(signal: &Self::Signal) -> bool {
+                signal.touched.get()
+            }
+            fn touch(signal: &Self::Signal) {
+                signal.touched.set(true)
+            }
+        }
+
+        #[cfg(feature = "thaw")]
+        impl FormComponent<HtmlElement<Input, (), ()>> for $ty {
+            fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+                let class = props.class_signal();
+                let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
+                view! {
+                    <thaw::Input
+                        input_type=thaw::InputType::$thaw_type
+                        class={class.get().map(|s| s.to_string())}
+                        id={props.id.map(|s| s.to_string()).or_else(|| props.name.clone().map(|s| s.to_string()))}
+                        name={props.name.map(|s| s.to_string())}
+                        on:input=move |ev| {
+                            props.signal.value.update(|value| *value = event_target_value(&ev));
+                            events.handle(ValidateOn::Input)
+                        }
+                        on:change=move |_| events.handle(ValidateOn::Change)
+                        on:focusout=move |_| events.handle(ValidateOn::Blur)
+                        prop:class={move || class.with(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                        prop:value={props.signal.value}
+                        input_style={props.style.map(|s| s.to_string())}
+                        value=props.signal.value
+                    />
+                }
+            }
+        }
+
+        #[cfg(not(feature = "thaw"))]
+        impl FormComponent<HtmlElement<Input, (), ()>> for $ty {
+            fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+                let class = props.class_signal();
+                let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
+                view! {
+                    <input
+                        type=$type
+                        class={class}
+                        id={props.id.or_else(|| props.name.clone())}
+                        name={props.name}
+                        required=props.input_attrs.required
+                        minlength=props.input_attrs.min_length.map(|x| x.to_string())
+                        maxlength=props.input_attrs.max_length.map(|x| x.to_string())
+                        pattern=props.input_attrs.pattern
+                        inputmode=props.input_attrs.input_mode.unwrap_or(Oco::Borrowed($input_mode))
+                        autocomplete=props.input_attrs.autocomplete
+                        spellcheck="false"
+                        on:input=move |ev| {
+                            props.signal.value.update(|value| *value = event_target_value(&ev));
+                            events.handle(ValidateOn::Input)
+                        }
+                        on:change=move |_| events.handle(ValidateOn::Change)
+                        on:focusout=move |_| events.handle(ValidateOn::Blur)
+                        prop:class={move || class.with(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                        prop:value={props.signal.value}
+                        style={props.style}
+                        value=props.signal.value
+                    />
+                }
+            }
+        }
+    )* };
+}
+
+from_str_impl!(
+    ::std::net::IpAddr { type: "text", inputmode: "text", thaw: Text },
+    ::std::net::Ipv4Addr { type: "text", inputmode: "decimal", thaw: Text },
+    ::std::net::Ipv6Addr { type: "text", inputmode: "text", thaw: Text },
+    ::std::net::SocketAddr { type: "text", inputmode: "text", thaw: Text },
+);
+
+#[cfg(feature = "url")]
+from_str_impl!(::url::Url { type: "url", inputmode: "url", thaw: Url });
+
+#[cfg(feature = "email_address")]
+from_str_impl!(::email_address::EmailAddress { type: "email", inputmode: "email", thaw: Email });
+
+pub use self::color::*;
+
+mod color {
+    use super::*;
+    use ::std::fmt;
+    use ::std::str::FromStr;
+
+    /// An RGBA color, parsed from and displayed as `#rrggbb`, or `#rrggbbaa` when it isn't opaque.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    pub struct Color {
+        pub r: u8,
+        pub g: u8,
+        pub b: u8,
+        pub a: u8,
+    }
+
+    /// Error returned when parsing a [`Color`] from text which isn't formatted as `#rrggbb` or `#rrggbbaa`.
+    #[derive(Clone, Copy, Debug, Error, PartialEq, Eq)]
+    #[error("expected a color formatted as #rrggbb or #rrggbbaa")]
+    pub struct ParseColorError;
+
+    /// Configuration for [`Color`] fields.
+    #[derive(Clone, Copy, Debug, Default, TypedBuilder)]
+    #[builder(field_defaults(default))]
+    pub struct ColorConfig {
+        /// display a text input holding the hex value next to the color picker
+        pub hex_input: bool,
+        /// display a slider for the opacity of the color, colors must be opaque without it
+        pub alpha: bool,
+    }
+
+    impl Color {
+        pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+            Self { r, g, b, a: u8::MAX }
+        }
+
+        pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+            Self { r, g, b, a }
+        }
+
+        /// The color without its opacity as `#rrggbb`, the value of an `<input type="color">`.
+        pub fn to_rgb_hex(&self) -> String {
+            format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+        }
+
+        /// The color with its opacity as `#rrggbbaa`, even when it is opaque.
+        pub fn to_rgba_hex(&self) -> String {
+            format!("{}{:02x}", self.to_rgb_hex(), self.a)
+        }
+    }
+
+    /// Opaque black, the initial value of `<input type="color">`.
+    impl Default for Color {
+        fn default() -> Self {
+            Self::rgb(0, 0, 0)
+        }
+    }
+
+    impl fmt::Display for Color {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&self.to_rgb_hex())?;
+            match self.a {
+                u8::MAX => Ok(()),
+                a => write!(f, "{a:02x}"),
+            }
+        }
+    }
+
+    impl FromStr for Color {
+        type Err = ParseColorError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let hex = s.trim().strip_prefix('#').ok_or(ParseColorError)?;
+            if !matches!(hex.len(), 6 | 8) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(ParseColorError);
+            }
+            let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| ParseColorError);
+            Ok(Self {
+                r: channel(0)?,
+                g: channel(2)?,
+                b: channel(4)?,
+                a: if hex.len() == 8 { channel(6)? } else { u8::MAX },
+            })
+        }
+    }
+
+    impl DefaultHtmlElement for Color {
+        type El = HtmlElement<Input, (), ()>;
+    }
+
+    impl FormField<HtmlElement<Input, (), ()>> for Color {
+        type Config = ColorConfig;
+        type Signal = FormFieldSignal<String>;
+
+        fn default_signal(_: &Self::Config, initial: Option<Self>) -> Self::Signal {
+            FormFieldSignal::new_with_default_value(initial.map(|x| x.to_string()))
+        }
+        fn is_default_value(signal: &Self::Signal) -> bool {
+            signal.value.with_untracked(|value| value.is_empty())
+        }
+        fn into_signal(self, _: &Self::Config, initial: Option<Self>) -> Self::Signal {
+            FormFieldSignal::new(self.to_string(), initial.map(|x| x.to_string()))
+        }
+        fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+            // an untouched color picker displays the default color
+            let color = signal
+                .value
+                .with_untracked(|value| match value.trim().is_empty() {
+                    true => Ok(Color::default()),
+                    false => value.parse::<Color>(),
+                })
+                .map_err(FormError::parse)?;
+            if !config.alpha && color.a != u8::MAX {
+                return Err(FormError::validation("must be opaque").with_code("opaque"));
+            }
+            Ok(color)
+        }
+        fn recurse(signal: &Self::Signal) {
+            signal.value.with_untracked(|_| {})
+        }
+        fn reset_initial_value(signal: &Self::Signal) {
+            signal
+                .value
+                .with_untracked(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
+        }
+        fn has_changed(signal: &Self::Signal) -> bool {
+            signal.has_changed_tracked()
+        }
+        fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
+            signal.error.with(|error| f(error.as_ref()))
+        }
+        fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+            signal.error.set(error)
+        }
+        fn is_pending(signal: &Self::Signal) -> bool {
+            signal.pending.get()
+        }
+        fn set_pending(signal: &Self::Signal, pending: bool) {
+            signal.pending.set(pending)
+        }
+        fn is_touched(signal: &Self::Signal) -> bool {
+            signal.touched.get()
+        }
+        fn touch(signal: &Self::Signal) {
+            signal.touched.set(true)
+        }
+    }
+
+    impl FormComponent<HtmlElement<Input, (), ()>> for Color {
+        fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+            let class = props.class_signal();
+            let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
+            let value = props.signal.value;
+            // the picker and the slider edit the last valid color while the hex input is being typed in
+            let color = move || value.with(|value| value.parse::<Color>().unwrap_or_default());
+            let ColorConfig { hex_input, alpha } = props.config;
+
+            // the color picker only holds `#rrggbb`, so the full color is submitted by a hidden input instead
+            view! {
+                <div style="display: flex; flex-direction: row; align-items: center; gap: 0.5rem;">
+                    <input
+                        type="hidden"
+                        name={props.name.clone()}
+                        value=move || color().to_rgba_hex()
+                    />
+                    <input
+                        type="color"
+                        class={class}
+                        id={props.id.or(props.name)}
+                        on:input=move |ev| {
+                            if let Ok(picked) = event_target_value(&ev).parse::<Color>() {
+                                value.set(Color { a: color().a, ..picked }.to_string());
+                            }
+                            events.handle(ValidateOn::Input)
+                        }
+                        on:change=move |_| events.handle(ValidateOn::Change)
+                        on:focusout=move |_| events.handle(ValidateOn::Blur)
+                        prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                        prop:value=move || color().to_rgb_hex()
+                        style={props.style}
+                    />
+                    {hex_input.then(|| view! {
+                        <input
+                            type="text"
+                            size="9"
+                            spellcheck="false"
+                            pattern=if alpha { "#[0-9a-fA-F]{6}([0-9a-fA-F]{2})?" } else { "#[0-9a-fA-F]{6}" }
+                            on:input=move |ev| {
+                                value.set(event_target_value(&ev));
+                                events.handle(ValidateOn::Input)
+                            }
+                            on:change=move |_| events.handle(ValidateOn::Change)
+                            on:focusout=move |_| events.handle(ValidateOn::Blur)
+                            prop:value=value
+                        />
+                    })}
+                    {alpha.then(|| view! {
+                        <input
+                            type="range"
+                            min="0"
+                            max="255"
+                            on:input=move |ev| {
+                                if let Ok(a) = event_target_value(&ev).parse::<u8>() {
+                                    value.set(Color { a, ..color() }.to_string());
+                                }
+                                events.handle(ValidateOn::Input)
+                            }
+                            on:change=move |_| events.handle(ValidateOn::Change)
+                            on:focusout=move |_| events.handle(ValidateOn::Blur)
+                            prop:value=move || color().a.to_string()
+                        />
+                    })}
+                </div>
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[test]
+        fn colors_are_displayed_as_hex() {
+            assert_eq!("#0a0b0c", Color::rgb(10, 11, 12).to_string());
+            assert_eq!("#0a0b0c80", Color::rgba(10, 11, 12, 128).to_string());
+            assert_eq!("#000000", Color::default().to_string());
+            assert_eq!("#0a0b0c", Color::rgba(10, 11, 12, 0).to_rgb_hex());
+            assert_eq!("#0a0b0cff", Color::rgb(10, 11, 12).to_rgba_hex());
+        }
+
+        #[test]
+        fn colors_are_parsed_from_hex() {
+            assert_eq!(Ok(Color::rgb(255, 0, 171)), "#ff00ab".parse());
+            assert_eq!(Ok(Color::rgb(255, 0, 171)), " #FF00AB ".parse());
+            assert_eq!(Ok(Color::rgba(255, 0, 171, 0)), "#ff00ab00".parse());
+            assert_eq!(Ok(Color::rgb(255, 0, 171)), "#ff00abff".parse());
+            assert_eq!(Err(ParseColorError), "ff00ab".parse::<Color>());
+            assert_eq!(Err(ParseColorError), "#f0a".parse::<Color>());
+            assert_eq!(Err(ParseColorError), "#ff00ab0".parse::<Color>());
+            assert_eq!(Err(ParseColorError), "#gg00ab".parse::<Color>());
+            assert_eq!(Err(ParseColorError), "#+f00ab".parse::<Color>());
+        }
+
+        #[test]
+        fn colors_round_trip() {
+            for color in [Color::rgb(1, 2, 3), Color::rgba(250, 128, 0, 77)] {
+                assert_eq!(Ok(color), color.to_string().parse());
+                assert_eq!(Ok(color), color.to_rgba_hex().parse());
+            }
+        }
+    }
+}
+
+/// Implements FormField for a date/time type entered as text in the format of its config,
+/// `$format` turns a value and its config into that text.
+macro_rules! datetime_impl {
+    (|$x:ident, $cfg:ident| $format:expr; $($ty:ty, $config:ty { $($from:tt)* }),*$(,)?) => { $(
+        datetime_impl! { @ |$x, $cfg| $format; $ty, $config { $($from)* } }
+    )* };
+
+    (@ |$x:ident, $cfg:ident| $format:expr; $ty:ty, $config:ty { $($from:tt)* }) => { paste! {
+        impl DefaultHtmlElement for $ty {
+            type El = HtmlElement<Input, (), ()>;
+        }
+
+        impl FormField<HtmlElement<Input, (), ()>> for $ty {
+            type Config = $config;
+            type Signal = FormFieldSignal<String>;
+
+            fn default_signal($cfg: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                FormFieldSignal::new_with_default_value(initial.map(|$x| $format))
+            }
+            fn is_default_value(signal: &Self::Signal) -> bool {
+                signal.value.with(|value| value.is_empty())
+            }
+            fn into_signal(self, $cfg: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                let format = |$x: Self| $format;
+                FormFieldSignal::new(format(self), initial.map(format))
+            }
+            fn recurse(signal: &Self::Signal) {
+                signal.with(|_| {})
+            }
+            fn reset_initial_value(signal: &Self::Signal) {
+                signal.value.with(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
+            }
+            fn has_changed(signal: &Self::Signal) -> bool {
+                signal.has_changed_tracked()
+            }
+            fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
+                signal.error.with(|error| f(error.as_ref()))
+            }
+            fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+                signal.error.set(error)
+            }
+            fn is_pending(signal: &Self::Signal) -> bool {
+                signal.pending.get()
+            }
+            fn set_pending(signal: &Self::Signal, pending: bool) {
+                signal.pending.set(pending)
+            }
+            fn is_touched(signal: &Self::Signal) -> bool {
+                signal.touched.get()
+            }
+            fn touch(signal: &Self::Signal) {
+                signal.touched.set(true)
+            }
+
+            $($from)*
+        }
+
+        #[cfg(feature = "thaw")]
+        impl FormComponent<HtmlElement<Input, (), ()>> for $ty {
+            fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+                let class = props.class_signal();
+                let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
+                view! {
+                    <thaw::Input
+                        input_type=thaw::InputType::Text
+                        class={class.get().map(|s| s.to_string())}
+                        id={props.id.map(|s| s.to_string()).or_else(|| props.name.clone().map(|s| s.to_string()))}
+                        name={props.name.map(|s| s.to_string())}
+                        on:input=move |ev| {
+                            props.signal.value.update(|value| *value = event_target_value(&ev));
+                            events.handle(ValidateOn::Input)
+                        }
+                        on:change=move |_| events.handle(ValidateOn::Change)
+                        on:focusout=move |_| events.handle(ValidateOn::Blur)
+                        prop:class={move || class.with(|x| x.as_ref().map(|x| JsValue::from_str(&*x)))}
+                        prop:value={props.signal.value}
+                        input_style={props.style.map(|s| s.to_string())}
+                        value=props.signal.value
+                    />
+                }
+            }
+        }
+        #[cfg(not(feature = "thaw"))]
+        impl FormComponent<HtmlElement<Input, (), ()>> for $ty {
+            fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+                let class = props.class_signal();
+                let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
+                view! {
+                    <input
+                        type="text"
+                        class={class}
+                        id={props.id.or_else(|| props.name.clone())}
+                        name={props.name}
+                        required=props.input_attrs.required
+                        minlength=props.input_attrs.min_length.map(|x| x.to_string())
+                        maxlength=props.input_attrs.max_length.map(|x| x.to_string())
+                        pattern=props.input_attrs.pattern
+                        step=props.input_attrs.step
+                        inputmode=props.input_attrs.input_mode
+                        autocomplete=props.input_attrs.autocomplete
+                        on:input=move |ev| {
+                            props.signal.value.update(|value| *value = event_target_value(&ev));
+                            events.handle(ValidateOn::Input)
+                        }
+                        on:change=move |_| events.handle(ValidateOn::Change)
+                        on:focusout=move |_| events.handle(ValidateOn::Blur)
+                        prop:class={move || class.with(|x| x.as_ref().map(|x| JsValue::from_str(&*x)))}
+                        prop:value={props.signal.value}
+                        style={props.style}
+                        value=props.signal.value
+                    />
+                }
+            }
+        }
+    } };
+}
+
 #[cfg(feature = "chrono")]
 /// Configuration utilities for using [`chrono`](::chrono) types in form types.
 pub mod chrono {
@@ -191,177 +730,738 @@ pub mod chrono {
     pub struct NaiveDateConfig {
         /// defaults to `"%F"`
         pub format: &'static str,
+        /// earliest accepted date
+        pub min: Option<NaiveDate>,
+        /// latest accepted date
+        pub max: Option<NaiveDate>,
+    }
+    #[derive(Clone, Debug)]
+    pub struct NaiveTimeConfig {
+        /// defaults to `"%T"`
+        pub format: &'static str,
+        /// earliest accepted time
+        pub min: Option<NaiveTime>,
+        /// latest accepted time
+        pub max: Option<NaiveTime>,
     }
     #[derive(Clone, Debug)]
     pub struct NaiveDateTimeConfig {
-        /// defaults to `"%FT%T"`
+        /// defaults to `"%FT%T"`, which also accepts `datetime-local` values without seconds
         pub format: &'static str,
+        /// earliest accepted date and time
+        pub min: Option<NaiveDateTime>,
+        /// latest accepted date and time
+        pub max: Option<NaiveDateTime>,
     }
     #[derive(Clone, Debug)]
     pub struct FixedOffsetDateTimeConfig {
         /// defaults to `"%+"`
         pub format: &'static str,
+        /// earliest accepted date and time
+        pub min: Option<DateTime<FixedOffset>>,
+        /// latest accepted date and time
+        pub max: Option<DateTime<FixedOffset>>,
     }
     #[derive(Clone, Debug)]
     pub struct UtcDateTimeConfig {
         /// defaults to `"%+"`
         pub format: &'static str,
+        /// earliest accepted date and time
+        pub min: Option<DateTime<Utc>>,
+        /// latest accepted date and time
+        pub max: Option<DateTime<Utc>>,
     }
     #[derive(Clone, Debug)]
     pub struct LocalDateTimeConfig {
-        /// defaults to `"%FT%T"`
+        /// defaults to `"%FT%T"`, which also accepts `datetime-local` values without seconds
+        ///
+        /// The format has no offset: values are read in the local time zone,
+        /// ambiguous times resolve to the earliest one.
         pub format: &'static str,
+        /// earliest accepted date and time
+        pub min: Option<DateTime<Local>>,
+        /// latest accepted date and time
+        pub max: Option<DateTime<Local>>,
     }
 
     impl Default for NaiveDateConfig {
         fn default() -> Self {
-            Self { format: "%F" }
+            Self {
+                format: "%F",
+                min: None,
+                max: None,
+            }
+        }
+    }
+    impl Default for NaiveTimeConfig {
+        fn default() -> Self {
+            Self {
+                format: "%T",
+                min: None,
+                max: None,
+            }
         }
     }
     impl Default for NaiveDateTimeConfig {
         fn default() -> Self {
-            Self { format: "%FT%T" }
+            Self {
+                format: "%FT%T",
+                min: None,
+                max: None,
+            }
         }
     }
     impl Default for FixedOffsetDateTimeConfig {
         fn default() -> Self {
-            Self { format: "%+" }
+            Self {
+                format: "%+",
+                min: None,
+                max: None,
+            }
         }
     }
     impl Default for UtcDateTimeConfig {
         fn default() -> Self {
-            Self { format: "%+" }
+            Self {
+                format: "%+",
+                min: None,
+                max: None,
+            }
         }
     }
     impl Default for LocalDateTimeConfig {
         fn default() -> Self {
-            Self { format: "%FT%T" }
+            Self {
+                format: "%FT%T",
+                min: None,
+                max: None,
+            }
         }
     }
 
-    macro_rules! chrono_impl {
-        ($($ty:ty, $config:ty { $($from:tt)* }),*$(,)?) => { $(
-            chrono_impl! { @ $ty, $config { $($from)* } }
+    /// Conversion between a value and the text of its input, shared by the fields of a type and ranges of it.
+    trait ParseWith: PartialOrd + Sized {
+        type Config;
+        fn format_with(&self, config: &Self::Config) -> String;
+        fn parse_with(text: &str, config: &Self::Config) -> Result<Self, FormError>;
+    }
+
+    macro_rules! parse_with_impl {
+        ($($ty:ty, $config:ty, |$text:ident, $format:ident| $parse:expr),*$(,)?) => { $(
+            impl ParseWith for $ty {
+                type Config = $config;
+                fn format_with(&self, config: &Self::Config) -> String {
+                    self.format(config.format).to_string()
+                }
+                fn parse_with(text: &str, config: &Self::Config) -> Result<Self, FormError> {
+                    let parse = |$text: &str, $format: &str| $parse;
+                    let value = parse(text, config.format)?;
+                    check_bounds(value, config.min.as_ref(), config.max.as_ref(), |x| x.format_with(config))
+                }
+            }
         )* };
+    }
+
+    parse_with_impl!(
+        NaiveDate,
+        NaiveDateConfig,
+        |text, format| NaiveDate::parse_from_str(text, format).map_err(FormError::parse),
+        NaiveTime,
+        NaiveTimeConfig,
+        |text, format| NaiveTime::parse_from_str(text, format).map_err(FormError::parse),
+        NaiveDateTime,
+        NaiveDateTimeConfig,
+        |text, format| parse_naive_date_time(text, format),
+        DateTime<FixedOffset>,
+        FixedOffsetDateTimeConfig,
+        |text, format| DateTime::parse_from_str(text, format).map_err(FormError::parse),
+        DateTime<Utc>,
+        UtcDateTimeConfig,
+        |text, format| {
+            DateTime::parse_from_str(text, format)
+                .map(|value| value.with_timezone(&Utc))
+                .map_err(FormError::parse)
+        },
+        DateTime<Local>,
+        LocalDateTimeConfig,
+        |text, format| in_time_zone(&Local, parse_naive_date_time(text, format)?),
+    );
+
+    /// The format of `datetime-local` input values, which omit the seconds of times at the start of a minute.
+    const DATETIME_LOCAL_FORMAT: &str = "%FT%T";
+
+    fn parse_naive_date_time(text: &str, format: &str) -> Result<NaiveDateTime, FormError> {
+        NaiveDateTime::parse_from_str(text, format)
+            .or_else(|err| match format == DATETIME_LOCAL_FORMAT {
+                true => NaiveDateTime::parse_from_str(text, "%FT%R").map_err(|_| err),
+                false => Err(err),
+            })
+            .map_err(FormError::parse)
+    }
+
+    /// Reads a date and time in `tz`, ambiguous times (when clocks are turned back) resolve to the earliest one.
+    fn in_time_zone<Tz: TimeZone>(tz: &Tz, value: NaiveDateTime) -> Result<DateTime<Tz>, FormError> {
+        tz.from_local_datetime(&value)
+            .earliest()
+            .ok_or_else(|| FormError::validation("does not exist in this time zone").with_code("time_zone"))
+    }
+
+    fn check_bounds<T: PartialOrd>(
+        value: T,
+        min: Option<&T>,
+        max: Option<&T>,
+        format: impl Fn(&T) -> String,
+    ) -> Result<T, FormError> {
+        if let Some(min) = min.filter(|min| value < **min) {
+            let min = format(min);
+            return Err(FormError::validation(format!("must not be before {min}"))
+                .with_code("range")
+                .with_param("min", min));
+        }
+        if let Some(max) = max.filter(|max| value > **max) {
+            let max = format(max);
+            return Err(FormError::validation(format!("must not be after {max}"))
+                .with_code("range")
+                .with_param("max", max));
+        }
+        Ok(value)
+    }
+
+    datetime_impl!(
+        |x, config| x.format_with(config);
+        NaiveDate, NaiveDateConfig {
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                signal.value.with(|value| Self::parse_with(value, config))
+            }
+        },
+        NaiveTime, NaiveTimeConfig {
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                signal.value.with(|value| Self::parse_with(value, config))
+            }
+        },
+        NaiveDateTime, NaiveDateTimeConfig {
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                signal.value.with(|value| Self::parse_with(value, config))
+            }
+        },
+        DateTime<FixedOffset>, FixedOffsetDateTimeConfig {
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                signal.value.with(|value| Self::parse_with(value, config))
+            }
+        },
+        DateTime<Utc>, UtcDateTimeConfig {
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                signal.value.with(|value| Self::parse_with(value, config))
+            }
+        },
+        DateTime<Local>, LocalDateTimeConfig {
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                signal.value.with(|value| Self::parse_with(value, config))
+            }
+        },
+    );
+
+    /// A period between two dates, both inclusive.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct DateRange {
+        pub start: NaiveDate,
+        pub end: NaiveDate,
+    }
+
+    /// A period between two dates and times.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct DateTimeRange {
+        pub start: NaiveDateTime,
+        pub end: NaiveDateTime,
+    }
+
+    /// Configuration for [`DateRange`] and [`DateTimeRange`] fields.
+    #[derive(Clone, Debug, Derivative, TypedBuilder)]
+    #[builder(field_defaults(default, setter(into)))]
+    #[derivative(Default(bound = "C: Default"))]
+    pub struct RangeConfig<C> {
+        /// format and bounds of both the start and the end
+        pub item: C,
+        /// displayed between the start and end inputs
+        #[builder(default = Oco::Borrowed("–"))]
+        #[derivative(Default(value = "Oco::Borrowed(\"–\")"))]
+        pub separator: Oco<'static, str>,
+    }
+
+    /// The text of the start and end inputs of a range.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    pub struct RangeInput {
+        pub start: String,
+        pub end: String,
+    }
+
+    macro_rules! range_impl {
+        ($($range:ident($ty:ty, $config:ty)),*$(,)?) => { $(
+            impl $range {
+                fn to_input(self, config: &$config) -> RangeInput {
+                    RangeInput {
+                        start: self.start.format_with(config),
+                        end: self.end.format_with(config),
+                    }
+                }
+            }
 
-        (@ $ty:ty, $config:ty { $($from:tt)* }) => { paste! {
-            impl DefaultHtmlElement for $ty {
+            impl DefaultHtmlElement for $range {
                 type El = HtmlElement<Input, (), ()>;
             }
 
-            impl FormField<HtmlElement<Input, (), ()>> for $ty {
-                type Config = $config;
-                type Signal = FormFieldSignal<String>;
+            impl FormField<HtmlElement<Input, (), ()>> for $range {
+                type Config = RangeConfig<$config>;
+                type Signal = FormFieldSignal<RangeInput>;
 
                 fn default_signal(config: &Self::Config, initial: Option<Self>) -> Self::Signal {
-                    FormFieldSignal::new_with_default_value(initial.map(|x| x.format(config.format).to_string()))
+                    FormFieldSignal::new_with_default_value(initial.map(|x| x.to_input(&config.item)))
                 }
                 fn is_default_value(signal: &Self::Signal) -> bool {
-                    signal.value.with(|value| value.is_empty())
+                    signal.value.with_untracked(|value| value.start.is_empty() && value.end.is_empty())
                 }
                 fn into_signal(self, config: &Self::Config, initial: Option<Self>) -> Self::Signal {
-                    FormFieldSignal::new(self.format(config.format).to_string(), initial.map(|initial| initial.format(config.format).to_string()))
+                    FormFieldSignal::new(self.to_input(&config.item), initial.map(|x| x.to_input(&config.item)))
+                }
+                fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                    signal.value.with_untracked(|value| {
+                        let mut errors = FormErrors::new();
+                        let start = errors.record(<$ty>::parse_with(&value.start, &config.item).map_err(|err| err.in_field("start")));
+                        let end = errors.record(<$ty>::parse_with(&value.end, &config.item).map_err(|err| err.in_field("end")));
+                        errors.into_result()?;
+                        let (start, end) = (start.expect("start was parsed successfully"), end.expect("end was parsed successfully"));
+                        if end < start {
+                            return Err(FormError::validation("must not end before it starts").with_code("date_range").in_field("end"));
+                        }
+                        Ok(Self { start, end })
+                    })
                 }
                 fn recurse(signal: &Self::Signal) {
-                    signal.with(|_| {})
+                    signal.value.with_untracked(|_| {})
                 }
                 fn reset_initial_value(signal: &Self::Signal) {
-                    signal.value.with(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
+                    signal.value.with_untracked(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
+                }
+                fn has_changed(signal: &Self::Signal) -> bool {
+                    signal.has_changed_tracked()
                 }
                 fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
                     signal.error.with(|error| f(error.as_ref()))
                 }
-
-                $($from)*
+                fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+                    signal.error.set(error)
+                }
+                fn is_pending(signal: &Self::Signal) -> bool {
+                    signal.pending.get()
+                }
+                fn set_pending(signal: &Self::Signal, pending: bool) {
+                    signal.pending.set(pending)
+                }
+                fn is_touched(signal: &Self::Signal) -> bool {
+                    signal.touched.get()
+                }
+                fn touch(signal: &Self::Signal) {
+                    signal.touched.set(true)
+                }
             }
 
-            #[cfg(feature = "thaw")]
-            impl FormComponent<HtmlElement<Input, (), ()>> for $ty {
+            impl FormComponent<HtmlElement<Input, (), ()>> for $range {
                 fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
-                    let class = props.class_signal();
-                    view! {
-                        <thaw::Input
-                            input_type=thaw::InputType::Text
-                            class={class.get().map(|s| s.to_string())}
-                            id={props.id.map(|s| s.to_string()).or_else(|| props.name.clone().map(|s| s.to_string()))}
-                            name={props.name.map(|s| s.to_string())}
-                            on:input=move |ev| props.signal.value.update(|value| *value = event_target_value(&ev))
-                            on:change=move |_| {
-                                if !props.is_optional || !Self::is_default_value(&props.signal) {
-                                    if let Err(form_error) = <Self as FormField<HtmlElement<Input, (), ()>>>::try_from_signal(props.signal, &props.config) {
-                                        props.signal.error.update(|error| *error = Some(form_error));
-                                    } else if props.signal.error.with_untracked(|error| error.is_some()) {
-                                        props.signal.error.update(|error| *error = None);
-                                    }
-                                } else {
-                                    props.signal.error.update(|error| *error = None);
-                                }
-                            }
-                            prop:class={move || class.with(|x| x.as_ref().map(|x| JsValue::from_str(&*x)))}
-                            prop:value={props.signal.value}
-                            input_style={props.style.map(|s| s.to_string())}
-                            value=props.signal.value
-                        />
+                    render_range::<Self, _>(props)
+                }
+            }
+        )* };
+    }
+
+    range_impl!(
+        DateRange(NaiveDate, NaiveDateConfig),
+        DateTimeRange(NaiveDateTime, NaiveDateTimeConfig),
+    );
+
+    fn render_range<T, C>(props: RenderProps<FormFieldSignal<RangeInput>, RangeConfig<C>>) -> impl IntoView
+    where
+        T: FormField<HtmlElement<Input, (), ()>, Signal = FormFieldSignal<RangeInput>, Config = RangeConfig<C>>,
+    {
+        let class = props.class_signal();
+        let events = FieldEvents::<T, HtmlElement<Input, (), ()>>::new(&props);
+        let value = props.signal.value;
+        let id = props.id.or_else(|| props.name.clone());
+        let start_name = props
+            .name
+            .as_ref()
+            .map(|name| crate::format_form_name(Some(name), "start"));
+        let end_name = props
+            .name
+            .as_ref()
+            .map(|name| crate::format_form_name(Some(name), "end"));
+        let required = props.input_attrs.required;
+
+        view! {
+            <div
+                role="group"
+                class={class}
+                id={id}
+                prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                style={props.style}
+            >
+                <input
+                    type="text"
+                    name={start_name}
+                    required=required
+                    on:input=move |ev| {
+                        value.update(|value| value.start = event_target_value(&ev));
+                        events.handle(ValidateOn::Input)
+                    }
+                    on:change=move |_| events.handle(ValidateOn::Change)
+                    on:focusout=move |_| events.handle(ValidateOn::Blur)
+                    prop:value=move || value.with(|value| value.start.clone())
+                />
+                <span>{props.config.separator}</span>
+                <input
+                    type="text"
+                    name={end_name}
+                    required=required
+                    on:input=move |ev| {
+                        value.update(|value| value.end = event_target_value(&ev));
+                        events.handle(ValidateOn::Input)
                     }
+                    on:change=move |_| events.handle(ValidateOn::Change)
+                    on:focusout=move |_| events.handle(ValidateOn::Blur)
+                    prop:value=move || value.with(|value| value.end.clone())
+                />
+            </div>
+        }
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    pub use self::tz::*;
+
+    #[cfg(feature = "chrono-tz")]
+    mod tz {
+        use super::*;
+        use ::chrono_tz::{Tz, TZ_VARIANTS};
+
+        /// Configuration for [`DateTime<Tz>`] fields, entered as a `datetime-local` input next to a time zone selector.
+        #[derive(Clone, Debug, Default, TypedBuilder)]
+        #[builder(field_defaults(default))]
+        pub struct TimeZoneDateTimeConfig {
+            /// the time zones which can be selected, every time zone when empty
+            #[builder(setter(into))]
+            pub time_zones: Vec<Tz>,
+            /// the time zone selected for new values, defaults to the first of `time_zones` or UTC when it is empty
+            #[builder(setter(strip_option))]
+            pub default_time_zone: Option<Tz>,
+            /// earliest accepted date and time
+            #[builder(setter(strip_option))]
+            pub min: Option<DateTime<Tz>>,
+            /// latest accepted date and time
+            #[builder(setter(strip_option))]
+            pub max: Option<DateTime<Tz>>,
+        }
+
+        /// The text of a `datetime-local` input and the name of the time zone it is read in.
+        #[derive(Clone, Debug, Default, PartialEq, Eq)]
+        pub struct TimeZoneDateTimeInput {
+            pub local: String,
+            pub time_zone: String,
+        }
+
+        impl TimeZoneDateTimeInput {
+            fn new(value: &DateTime<Tz>) -> Self {
+                Self {
+                    local: value.naive_local().format(DATETIME_LOCAL_FORMAT).to_string(),
+                    time_zone: value.timezone().name().to_string(),
                 }
             }
-            #[cfg(not(feature = "thaw"))]
-            impl FormComponent<HtmlElement<Input, (), ()>> for $ty {
-                fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
-                    let class = props.class_signal();
-                    view! {
+        }
+
+        impl DefaultHtmlElement for DateTime<Tz> {
+            type El = HtmlElement<Input, (), ()>;
+        }
+
+        impl FormField<HtmlElement<Input, (), ()>> for DateTime<Tz> {
+            type Config = TimeZoneDateTimeConfig;
+            type Signal = FormFieldSignal<TimeZoneDateTimeInput>;
+
+            fn default_signal(config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                let signal = FormFieldSignal::new_with_default_value(initial.as_ref().map(TimeZoneDateTimeInput::new));
+                let time_zone = config
+                    .default_time_zone
+                    .or_else(|| config.time_zones.first().copied())
+                    .unwrap_or(Tz::UTC);
+                signal
+                    .value
+                    .update_untracked(|value| value.time_zone = time_zone.name().to_string());
+                signal
+            }
+            fn is_default_value(signal: &Self::Signal) -> bool {
+                signal.value.with_untracked(|value| value.local.is_empty())
+            }
+            fn into_signal(self, _: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                FormFieldSignal::new(
+                    TimeZoneDateTimeInput::new(&self),
+                    initial.as_ref().map(TimeZoneDateTimeInput::new),
+                )
+            }
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                signal.value.with_untracked(|value| {
+                    let local = parse_naive_date_time(&value.local, DATETIME_LOCAL_FORMAT)?;
+                    let time_zone = value.time_zone.parse::<Tz>().map_err(FormError::parse)?;
+                    let value = in_time_zone(&time_zone, local)?;
+                    check_bounds(value, config.min.as_ref(), config.max.as_ref(), |x| x.to_rfc3339())
+                })
+            }
+            fn recurse(signal: &Self::Signal) {
+                signal.value.with_untracked(|_| {})
+            }
+            fn reset_initial_value(signal: &Self::Signal) {
+                signal
+                    .value
+                    .with_untracked(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
+            }
+            fn has_changed(signal: &Self::Signal) -> bool {
+                signal.has_changed_tracked()
+            }
+            fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
+                signal.error.with(|error| f(error.as_ref()))
+            }
+            fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+                signal.error.set(error)
+            }
+            fn is_pending(signal: &Self::Signal) -> bool {
+                signal.pending.get()
+            }
+            fn set_pending(signal: &Self::Signal, pending: bool) {
+                signal.pending.set(pending)
+            }
+            fn is_touched(signal: &Self::Signal) -> bool {
+                signal.touched.get()
+            }
+            fn touch(signal: &Self::Signal) {
+                signal.touched.set(true)
+            }
+        }
+
+        impl FormComponent<HtmlElement<Input, (), ()>> for DateTime<Tz> {
+            fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+                let class = props.class_signal();
+                let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
+                let value = props.signal.value;
+                let time_zones = match props.config.time_zones.is_empty() {
+                    true => TZ_VARIANTS.to_vec(),
+                    false => props.config.time_zones.clone(),
+                };
+                let min = props
+                    .config
+                    .min
+                    .map(|min| min.naive_local().format(DATETIME_LOCAL_FORMAT).to_string());
+                let max = props
+                    .config
+                    .max
+                    .map(|max| max.naive_local().format(DATETIME_LOCAL_FORMAT).to_string());
+
+                view! {
+                    <div style="display: flex; flex-direction: row; gap: 0.5rem;">
                         <input
-                            type="text"
+                            type="datetime-local"
+                            step="1"
                             class={class}
                             id={props.id.or_else(|| props.name.clone())}
-                            name={props.name}
-                            on:input=move |ev| props.signal.value.update(|value| *value = event_target_value(&ev))
-                            on:change=move |_| {
-                                if !props.is_optional || !Self::is_default_value(&props.signal) {
-                                    if let Err(form_error) = <Self as FormField<HtmlElement<Input, (), ()>>>::try_from_signal(props.signal, &props.config) {
-                                        props.signal.error.update(|error| *error = Some(form_error));
-                                    } else if props.signal.error.with_untracked(|error| error.is_some()) {
-                                        props.signal.error.update(|error| *error = None);
-                                    }
-                                } else {
-                                    props.signal.error.update(|error| *error = None);
-                                }
+                            min=min
+                            max=max
+                            name={props.name.clone()}
+                            required=props.input_attrs.required
+                            on:input=move |ev| {
+                                value.update(|value| value.local = event_target_value(&ev));
+                                events.handle(ValidateOn::Input)
                             }
-                            prop:class={move || class.with(|x| x.as_ref().map(|x| JsValue::from_str(&*x)))}
-                            prop:value={props.signal.value}
+                            on:change=move |_| events.handle(ValidateOn::Change)
+                            on:focusout=move |_| events.handle(ValidateOn::Blur)
+                            prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                            prop:value=move || value.with(|value| value.local.clone())
                             style={props.style}
-                            value=props.signal.value
                         />
-                    }
+                        <select
+                            name={props.name.map(|name| crate::format_form_name(Some(&name), "time_zone"))}
+                            on:input=move |ev| {
+                                value.update(|value| value.time_zone = event_target_value(&ev));
+                                events.handle(ValidateOn::Input)
+                            }
+                            on:change=move |_| events.handle(ValidateOn::Change)
+                            on:focusout=move |_| events.handle(ValidateOn::Blur)
+                            prop:value=move || value.with(|value| value.time_zone.clone())
+                        >
+                            {time_zones.into_iter().map(|time_zone| view! {
+                                <option
+                                    value=time_zone.name()
+                                    selected=move || value.with(|value| value.time_zone == time_zone.name())
+                                >
+                                    {time_zone.name()}
+                                </option>
+                            }).collect_view()}
+                        </select>
+                    </div>
                 }
             }
-        } };
+        }
     }
+}
 
-    chrono_impl!(
-        NaiveDate, NaiveDateConfig {
+#[cfg(feature = "time")]
+/// Configuration utilities for using [`time`](::time) types in form types.
+pub mod time {
+    use super::*;
+    use ::time::format_description::BorrowedFormatItem;
+    use ::time::macros::format_description;
+    use ::time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+    #[derive(Clone, Debug)]
+    pub struct DateConfig {
+        /// defaults to `[year]-[month]-[day]`
+        pub format: &'static [BorrowedFormatItem<'static>],
+    }
+    #[derive(Clone, Debug)]
+    pub struct TimeConfig {
+        /// defaults to `[hour]:[minute]:[second]`
+        pub format: &'static [BorrowedFormatItem<'static>],
+    }
+    #[derive(Clone, Debug)]
+    pub struct PrimitiveDateTimeConfig {
+        /// defaults to `[year]-[month]-[day]T[hour]:[minute]:[second]`
+        pub format: &'static [BorrowedFormatItem<'static>],
+    }
+    #[derive(Clone, Debug)]
+    pub struct OffsetDateTimeConfig {
+        /// defaults to `[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]`
+        pub format: &'static [BorrowedFormatItem<'static>],
+    }
+
+    impl Default for DateConfig {
+        fn default() -> Self {
+            Self {
+                format: format_description!("[year]-[month]-[day]"),
+            }
+        }
+    }
+    impl Default for TimeConfig {
+        fn default() -> Self {
+            Self {
+                format: format_description!("[hour]:[minute]:[second]"),
+            }
+        }
+    }
+    impl Default for PrimitiveDateTimeConfig {
+        fn default() -> Self {
+            Self {
+                format: format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]"),
+            }
+        }
+    }
+    impl Default for OffsetDateTimeConfig {
+        fn default() -> Self {
+            Self {
+                format: format_description!(
+                    "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]"
+                ),
+            }
+        }
+    }
+
+    // formatting only fails when the format requires components the type doesn't have, e.g. an offset of a Date
+    datetime_impl!(
+        |x, config| x.format(config.format).unwrap_or_default();
+        Date, DateConfig {
             fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
-                signal.value.with(|value| Self::parse_from_str(value, config.format)).map_err(FormError::parse)
+                signal.value.with(|value| Self::parse(value, config.format)).map_err(FormError::parse)
             }
         },
-        NaiveDateTime, NaiveDateTimeConfig {
+        Time, TimeConfig {
             fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
-                signal.value.with(|value| Self::parse_from_str(value, config.format)).map_err(FormError::parse)
+                signal.value.with(|value| Self::parse(value, config.format)).map_err(FormError::parse)
             }
         },
-        DateTime<FixedOffset>, FixedOffsetDateTimeConfig {
+        PrimitiveDateTime, PrimitiveDateTimeConfig {
             fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
-                signal.value.with(|value| DateTime::parse_from_str(value, config.format)).map_err(FormError::parse)
+                signal.value.with(|value| Self::parse(value, config.format)).map_err(FormError::parse)
             }
         },
-        DateTime<Utc>, UtcDateTimeConfig {
+        OffsetDateTime, OffsetDateTimeConfig {
             fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
-                signal.value.with(|value| DateTime::parse_from_str(value, config.format).map(|value| value.with_timezone(&Utc))).map_err(FormError::parse)
+                signal.value.with(|value| Self::parse(value, config.format)).map_err(FormError::parse)
             }
         },
-        DateTime<Local>, LocalDateTimeConfig {
+    );
+}
+
+#[cfg(feature = "jiff")]
+/// Configuration utilities for using [`jiff`](::jiff) types in form types.
+pub mod jiff {
+    use super::*;
+    use ::jiff::civil::{Date, DateTime, Time};
+    use ::jiff::Zoned;
+
+    #[derive(Clone, Debug)]
+    pub struct CivilDateConfig {
+        /// defaults to `"%F"`
+        pub format: &'static str,
+    }
+    #[derive(Clone, Debug)]
+    pub struct CivilTimeConfig {
+        /// defaults to `"%T"`
+        pub format: &'static str,
+    }
+    #[derive(Clone, Debug)]
+    pub struct CivilDateTimeConfig {
+        /// defaults to `"%FT%T"`
+        pub format: &'static str,
+    }
+    #[derive(Clone, Debug)]
+    pub struct ZonedConfig {
+        /// defaults to `"%FT%T%:z[%Q]"`, parsing requires a time zone (`%Q`) or an offset (`%z`)
+        pub format: &'static str,
+    }
+
+    impl Default for CivilDateConfig {
+        fn default() -> Self {
+            Self { format: "%F" }
+        }
+    }
+    impl Default for CivilTimeConfig {
+        fn default() -> Self {
+            Self { format: "%T" }
+        }
+    }
+    impl Default for CivilDateTimeConfig {
+        fn default() -> Self {
+            Self { format: "%FT%T" }
+        }
+    }
+    impl Default for ZonedConfig {
+        fn default() -> Self {
+            Self { format: "%FT%T%:z[%Q]" }
+        }
+    }
+
+    datetime_impl!(
+        |x, config| x.strftime(config.format).to_string();
+        Date, CivilDateConfig {
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                signal.value.with(|value| Self::strptime(config.format, value)).map_err(FormError::parse)
+            }
+        },
+        Time, CivilTimeConfig {
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                signal.value.with(|value| Self::strptime(config.format, value)).map_err(FormError::parse)
+            }
+        },
+        DateTime, CivilDateTimeConfig {
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                signal.value.with(|value| Self::strptime(config.format, value)).map_err(FormError::parse)
+            }
+        },
+        Zoned, ZonedConfig {
             fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
-                signal.value.with(|value| DateTime::parse_from_str(value, config.format).map(|value| value.with_timezone(&Local))).map_err(FormError::parse)
+                signal.value.with(|value| Self::strptime(config.format, value)).map_err(FormError::parse)
             }
         },
     );
@@ -1,14 +1,33 @@
+mod array;
+mod bool;
+mod choice;
+mod duration;
+mod file;
+mod map;
 mod misc;
 mod num;
+mod secret;
+mod set;
 mod str;
+mod tuple;
 
 /// Field configuration utilities
 pub mod config {
+    pub use super::bool::*;
+    pub use super::choice::*;
+    pub use super::duration::*;
+    pub use super::file::*;
     #[allow(unused_imports)]
     pub use super::misc::*;
+    pub use super::num::{ExcessPrecision, NumConfig, NumLocale, NumPresentation, Slider, SliderConfig};
+    pub use super::secret::*;
 
     /// Configuration utilities for using collections in form types.
     pub mod collections {
+        pub use super::super::map::{MapConfig, MapEntrySignal};
+        pub use super::super::set::{CheckboxList, SetConfig};
+        pub use super::super::tuple::TupleConfig;
+
         include!("./collections.rs");
     }
 }
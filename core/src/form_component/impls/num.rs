@@ -1,8 +1,318 @@
 use crate::*;
 use ::leptos::html::*;
 use ::leptos::prelude::*;
+use ::std::num::*;
+use ::std::str::FromStr;
 use ::wasm_bindgen::{JsCast, JsValue};
 
+type NumInput = HtmlElement<Input, (), ()>;
+
+/// Configuration for numeric fields.
+#[derive(Clone, Debug, Derivative, TypedBuilder)]
+#[builder(field_defaults(default, setter(strip_option)))]
+#[derivative(Default(bound = ""))]
+pub struct NumConfig<T> {
+    /// smallest accepted value, also set as the input's `min` attribute
+    pub min: Option<T>,
+    /// largest accepted value, also set as the input's `max` attribute
+    pub max: Option<T>,
+    /// granularity of the input's `step` attribute, defaults to one unit of the last allowed decimal place
+    pub step: Option<T>,
+    /// maximum number of decimal places
+    pub precision: Option<u32>,
+    /// what happens to values with more decimal places than `precision`
+    #[builder(setter(!strip_option))]
+    pub excess_precision: ExcessPrecision,
+    /// separators used to display the value and parse it back, plain `1234.56` when unset
+    pub locale: Option<NumLocale>,
+    /// how the value is presented, e.g. as a percentage or an amount of a currency
+    #[builder(setter(!strip_option, into))]
+    pub presentation: NumPresentation,
+}
+
+/// The decimal and digit group separators of a locale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NumLocale {
+    pub decimal_separator: char,
+    pub group_separator: Option<char>,
+}
+
+/// How the value of a numeric field is presented.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NumPresentation {
+    #[default]
+    Plain,
+    /// The value is a fraction displayed as a percentage, e.g. `0.125` is displayed as `12.5%`.
+    Percent,
+    /// The value is displayed after a currency symbol, e.g. `$1,234.56`.
+    Currency(Oco<'static, str>),
+    /// The value is displayed before a currency symbol, e.g. `1.234,56 €`.
+    CurrencySuffix(Oco<'static, str>),
+}
+
+/// How a value with more decimal places than [`NumConfig::precision`] is handled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ExcessPrecision {
+    /// Fail with the error code `precision`.
+    #[default]
+    Reject,
+    /// Round to `precision` decimal places.
+    Round,
+}
+
+impl<T: ToString> NumConfig<T> {
+    fn step_attr(&self) -> Option<Oco<'static, str>> {
+        match (&self.step, self.precision) {
+            (Some(step), _) => Some(Oco::Owned(step.to_string())),
+            (None, Some(0)) => Some(Oco::Borrowed("1")),
+            (None, Some(precision)) => Some(Oco::Owned(format!("0.{}1", "0".repeat(precision as usize - 1)))),
+            (None, None) => None,
+        }
+    }
+
+    /// Checks the number of decimal places of `text` against the precision,
+    /// returns whether the parsed value must be rounded.
+    fn check_precision(&self, text: &str) -> Result<bool, FormError> {
+        let Some(precision) = self.precision else {
+            return Ok(false);
+        };
+        let mantissa = text.split(['e', 'E']).next().unwrap_or_default();
+        let decimal_places = mantissa.split_once('.').map(|(_, x)| x.len()).unwrap_or_default();
+        if decimal_places <= precision as usize {
+            return Ok(false);
+        }
+        match self.excess_precision {
+            ExcessPrecision::Round => Ok(true),
+            ExcessPrecision::Reject => Err(FormError::validation(format!(
+                "must have at most {precision} decimal places"
+            ))
+            .with_code("precision")
+            .with_param("precision", precision)),
+        }
+    }
+}
+
+impl<T> NumConfig<T> {
+    /// Whether the displayed text differs from the plain representation of the value,
+    /// in which case it is entered in a text input.
+    fn is_localized(&self) -> bool {
+        self.locale.is_some() || self.presentation != NumPresentation::Plain
+    }
+
+    /// Formats the plain representation of a value (as produced by `ToString`) for display.
+    pub fn localize(&self, plain: String) -> String {
+        let plain = match self.presentation {
+            NumPresentation::Percent => shift_decimal_point(&plain, 2),
+            _ => plain,
+        };
+        let number = match self.locale {
+            Some(locale) => locale.format(&plain),
+            None => plain,
+        };
+        match &self.presentation {
+            NumPresentation::Plain => number,
+            NumPresentation::Percent => format!("{number}%"),
+            NumPresentation::Currency(symbol) => format!("{symbol}{number}"),
+            NumPresentation::CurrencySuffix(symbol) => format!("{number} {symbol}"),
+        }
+    }
+
+    /// Turns displayed text back into the plain representation of its value, ready to be parsed with `FromStr`.
+    /// Text which isn't a number is returned as is so that parsing reports the error.
+    pub fn normalize(&self, text: &str) -> String {
+        let text = text.trim();
+        let text = match &self.presentation {
+            NumPresentation::Plain => text,
+            NumPresentation::Percent => text.strip_suffix('%').unwrap_or(text),
+            NumPresentation::Currency(symbol) => text.strip_prefix(&**symbol).unwrap_or(text),
+            NumPresentation::CurrencySuffix(symbol) => text.strip_suffix(&**symbol).unwrap_or(text),
+        };
+        let text = match self.locale {
+            Some(locale) => locale.normalize(text),
+            None => text.trim().to_string(),
+        };
+        match self.presentation {
+            NumPresentation::Percent => shift_decimal_point(&text, -2),
+            _ => text,
+        }
+    }
+}
+
+impl<T: FromStr + ToString> NumConfig<T> {
+    /// Reformats displayed text once it holds a valid value, e.g. `1234,5` becomes `1.234,5` for [`NumLocale::DE`].
+    fn reformat(&self, text: &str) -> Option<String> {
+        let value = self.normalize(text).parse::<T>().ok()?;
+        Some(self.localize(value.to_string()))
+    }
+}
+
+impl NumLocale {
+    /// `1,234.56`
+    pub const EN: Self = Self::new('.', Some(','));
+    /// `1.234,56`
+    pub const DE: Self = Self::new(',', Some('.'));
+    /// `1 234,56`, grouped with a narrow no-break space
+    pub const FR: Self = Self::new(',', Some('\u{202f}'));
+    /// `1'234.56`
+    pub const CH: Self = Self::new('.', Some('\''));
+
+    pub const fn new(decimal_separator: char, group_separator: Option<char>) -> Self {
+        Self {
+            decimal_separator,
+            group_separator,
+        }
+    }
+
+    /// Formats the plain representation of a number with this locale's separators.
+    pub fn format(&self, plain: &str) -> String {
+        let (sign, unsigned) = split_sign(plain);
+        let (int, frac) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if int.is_empty() || !int.chars().all(|c| c.is_ascii_digit()) {
+            return plain.to_string();
+        }
+        let mut formatted = sign.to_string();
+        for (i, digit) in int.chars().enumerate() {
+            if i > 0 && (int.len() - i) % 3 == 0 {
+                formatted.extend(self.group_separator);
+            }
+            formatted.push(digit);
+        }
+        if !frac.is_empty() {
+            formatted.push(self.decimal_separator);
+            formatted.push_str(frac);
+        }
+        formatted
+    }
+
+    /// Removes group separators and whitespace from `text` and replaces its decimal separator with `.`.
+    pub fn normalize(&self, text: &str) -> String {
+        text.chars()
+            .filter(|c| Some(*c) != self.group_separator && !c.is_whitespace())
+            .map(|c| match c == self.decimal_separator {
+                true => '.',
+                false => c,
+            })
+            .collect()
+    }
+}
+
+fn split_sign(text: &str) -> (&str, &str) {
+    match text.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", text.strip_prefix('+').unwrap_or(text)),
+    }
+}
+
+/// Moves the decimal point of a plain decimal number by `places` to the right (or left when negative),
+/// e.g. to display a fraction as a percentage.
+fn shift_decimal_point(text: &str, places: isize) -> String {
+    let (sign, unsigned) = split_sign(text);
+    let is_decimal = !unsigned.is_empty()
+        && unsigned.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && unsigned.matches('.').count() <= 1;
+    if !is_decimal {
+        return text.to_string();
+    }
+    let (int, frac) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let mut digits = format!("{int}{frac}");
+    let mut point = int.len() as isize + places;
+    if point < 0 {
+        digits.insert_str(0, &"0".repeat(point.unsigned_abs()));
+        point = 0;
+    }
+    let point = point as usize;
+    if point > digits.len() {
+        digits.push_str(&"0".repeat(point - digits.len()));
+    }
+    let (int, frac) = digits.split_at(point);
+    let int = match int.trim_start_matches('0') {
+        "" => "0",
+        int => int,
+    };
+    match frac.trim_end_matches('0') {
+        "" => format!("{sign}{int}"),
+        frac => format!("{sign}{int}.{frac}"),
+    }
+}
+
+/// Element type rendering a number as a range slider, see [`SliderConfig`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Slider;
+
+/// Configuration for numeric fields rendered as a [`Slider`].
+#[derive(Clone, Debug, Derivative, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[derivative(Default(bound = ""))]
+pub struct SliderConfig<T> {
+    /// parsing and bounds of the value, the slider spans from `min` to `max` (0 to 100 when unset) by `step`
+    pub num: NumConfig<T>,
+    /// display the current value next to the slider
+    #[builder(default = true)]
+    #[derivative(Default(value = "true"))]
+    pub readout: bool,
+    /// pair the slider with a number input for entering exact values
+    pub number_box: bool,
+    /// value an untouched slider sits at, defaults to `num.min` (or 0 when unset)
+    #[builder(setter(strip_option))]
+    pub default_value: Option<T>,
+}
+
+impl<T: Clone + Default> SliderConfig<T> {
+    /// The value an untouched slider sits at, see [`SliderConfig::default_value`].
+    fn start(&self) -> T {
+        self.default_value
+            .clone()
+            .or_else(|| self.num.min.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Rounding of parsed values to [`NumConfig::precision`], integers have no decimal places to round.
+trait Round: Sized {
+    fn round_to(self, _precision: u32) -> Self {
+        self
+    }
+}
+
+impl Round for u8 {}
+impl Round for u16 {}
+impl Round for u32 {}
+impl Round for u64 {}
+impl Round for u128 {}
+impl Round for usize {}
+impl Round for i8 {}
+impl Round for i16 {}
+impl Round for i32 {}
+impl Round for i64 {}
+impl Round for i128 {}
+impl Round for isize {}
+
+impl Round for f32 {
+    fn round_to(self, precision: u32) -> Self {
+        let factor = 10f32.powi(precision as i32);
+        (self * factor).round() / factor
+    }
+}
+
+impl Round for f64 {
+    fn round_to(self, precision: u32) -> Self {
+        let factor = 10f64.powi(precision as i32);
+        (self * factor).round() / factor
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl Round for num_bigint::BigInt {}
+#[cfg(feature = "num-bigint")]
+impl Round for num_bigint::BigUint {}
+
+#[cfg(feature = "bigdecimal")]
+impl Round for bigdecimal::BigDecimal {
+    fn round_to(self, precision: u32) -> Self {
+        self.round(precision as i64)
+    }
+}
+
 macro_rules! num_impl {
     ($($ty:ty $({ $(type: $type:literal)?$(,)? $(min: $min:expr, max: $max:expr)?$(,)? })? ),*$(,)?) => { $(
         impl DefaultHtmlElement for $ty {
@@ -10,21 +320,32 @@ macro_rules! num_impl {
         }
 
         impl FormField<HtmlElement<Input, (), ()>> for $ty {
-            type Config = ();
-            type Signal = FormFieldSignal<$ty>;
+            type Config = NumConfig<$ty>;
+            type Signal = FormFieldSignal<String>;
 
-            fn default_signal(_: &Self::Config, initial: Option<Self>) -> Self::Signal {
-                FormFieldSignal::new_with_default_value(initial)
+            fn default_signal(config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                FormFieldSignal::new_with_default_value(initial.map(|x| config.localize(x.to_string())))
             }
-            fn is_default_value(_signal: &Self::Signal) -> bool {
-                false
-                // signal.value.with_untracked(|value| value == $ty::Default())
+            fn is_default_value(signal: &Self::Signal) -> bool {
+                signal.value.with_untracked(|value| value.is_empty())
             }
-            fn into_signal(self, _: &Self::Config, initial: Option<Self>) -> Self::Signal {
-                FormFieldSignal::new(self, initial)
+            fn into_signal(self, config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                FormFieldSignal::new(
+                    config.localize(self.to_string()),
+                    initial.map(|x| config.localize(x.to_string())),
+                )
             }
-            fn try_from_signal(signal: Self::Signal, _: &Self::Config) -> Result<Self, FormError> {
-                Ok(signal.value.with_untracked(|value| *value))
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                let (value, round) = signal.value.with_untracked(|value| {
+                    let value = config.normalize(value);
+                    Ok::<_, FormError>((value.parse::<$ty>().map_err(FormError::parse)?, config.check_precision(&value)?))
+                })?;
+                let value = match (round, config.precision) {
+                    (true, Some(precision)) => value.round_to(precision),
+                    _ => value,
+                };
+                crate::validation::range(&value, config.min.clone(), config.max.clone())?;
+                Ok(value)
             }
             fn recurse(signal: &Self::Signal) {
                 signal.value.with_untracked(|_| {})
@@ -32,8 +353,26 @@ macro_rules! num_impl {
             fn reset_initial_value(signal: &Self::Signal) {
                 signal.value.with_untracked(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
             }
+            fn has_changed(signal: &Self::Signal) -> bool {
+                signal.has_changed_tracked()
+            }
             fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
-                signal.error.with_untracked(|error| f(error.as_ref()))
+                signal.error.with(|error| f(error.as_ref()))
+            }
+            fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+                signal.error.set(error)
+            }
+            fn is_pending(signal: &Self::Signal) -> bool {
+                signal.pending.get()
+            }
+            fn set_pending(signal: &Self::Signal, pending: bool) {
+                signal.pending.set(pending)
+            }
+            fn is_touched(signal: &Self::Signal) -> bool {
+                signal.touched.get()
+            }
+            fn touch(signal: &Self::Signal) {
+                signal.touched.set(true)
             }
         }
 
@@ -41,35 +380,53 @@ macro_rules! num_impl {
         impl FormComponent<HtmlElement<Input, (), ()>> for $ty {
             fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
                 let class = props.class_signal();
+                let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
+                let min = props.config.min.clone().unwrap_or(num_impl!(@min $ty $($(, $min)?)?));
+                let max = props.config.max.clone().unwrap_or(num_impl!(@max $ty $($(, $max)?)?));
+                let step = props.config.step_attr().and_then(|step| step.parse::<$ty>().ok()).unwrap_or(1 as $ty);
+                let required = props.is_required();
+                let config = StoredValue::new(props.config);
+                // the spin button holds the parsed number while the field keeps the text it was given,
+                // bounds and precision are checked when the text is parsed
+                let number = RwSignal::new(props.signal.value.with_untracked(|value| config.with_value(|config| config.normalize(value)).parse::<$ty>().unwrap_or_default()));
+                Effect::watch(
+                    move || number.get(),
+                    move |number, _, _| {
+                        let is_unchanged = props.signal.value.with_untracked(|value| {
+                            config.with_value(|config| config.normalize(value)).parse::<$ty>().is_ok_and(|value| value == *number)
+                        });
+                        if !is_unchanged {
+                            props.signal.value.set(config.with_value(|config| config.localize(number.to_string())));
+                            events.handle(ValidateOn::Input);
+                            events.handle(ValidateOn::Change);
+                        }
+                    },
+                    false,
+                );
                 view! {
                     <thaw::SpinButton< $ty >
                         // type=num_impl!(@type $($($type)?)?)
                         class={class.get().map(|s| s.to_string())}
                         id={props.id.map(|s| s.to_string()).or_else(|| props.name.clone().map(|s| s.to_string()))}
-                        max=num_impl!(@max $ty $($(, $max)?)?)
-                        min=num_impl!(@min $ty $($(, $min)?)?)
-                        step_page=(1 as $ty)
+                        max=max
+                        min=min
+                        step_page=step
                         name={props.name.map(|s| s.to_string())}
+                        attr:required=required
+                        attr:pattern=props.input_attrs.pattern
+                        attr:inputmode=props.input_attrs.input_mode.or(Some(Oco::Borrowed(num_impl!(@input_mode $($($type)?)?))))
+                        attr:autocomplete=props.input_attrs.autocomplete
                         on:keydown=num_impl!(@prevent_invalid_keystrokes value $($($type)?)?)
                         on:input=move |ev| {
                             let target_value = ev.target().unwrap().unchecked_into::<web_sys::HtmlInputElement>().value();
-                            props.signal.value.update(|value| *value = target_value.parse().unwrap_or_default())
-                        }
-                        on:change=move |_| {
-                            if !props.is_optional || !<Self as FormField<HtmlElement<Input, (), ()>>>::is_default_value(&props.signal) {
-                                if let Err(form_error) = <Self as FormField<HtmlElement<Input, (), ()>>>::try_from_signal(props.signal, &props.config) {
-                                    props.signal.error.update(|error| *error = Some(form_error));
-                                } else if props.signal.error.with_untracked(|error| error.is_some()) {
-                                    props.signal.error.update(|error| *error = None);
-                                }
-                            } else {
-                                props.signal.error.update(|error| *error = None);
-                            }
+                            props.signal.value.update(|value| *value = target_value);
+                            events.handle(ValidateOn::Input)
                         }
+                        on:change=move |_| events.handle(ValidateOn::Change)
+                        on:focusout=move |_| events.handle(ValidateOn::Blur)
                         prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(&*x)))}
-                        prop:value={props.signal.value}
                         // style={props.style}
-                        value=props.signal.value
+                        value=number
                     />
                 }
             }
@@ -78,30 +435,48 @@ macro_rules! num_impl {
         impl FormComponent<HtmlElement<Input, (), ()>> for $ty {
             fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
                 let class = props.class_signal();
+                let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
+                let required = props.is_required();
+                let min = props.config.min.as_ref().map(|min| min.to_string()).or_else(|| num_impl!(@min_attr $ty $($(, $min)?)?));
+                let max = props.config.max.as_ref().map(|max| max.to_string()).or_else(|| num_impl!(@max_attr $ty $($(, $max)?)?));
+                let step = props.config.step_attr().or(props.input_attrs.step);
+                // localized text can't be entered in a number input, nor with the keystrokes of a plain number
+                let is_localized = props.config.is_localized();
+                let (input_type, input_mode) = match is_localized {
+                    true => ("text", "decimal"),
+                    false => (num_impl!(@type $($($type)?)?), num_impl!(@input_mode $($($type)?)?)),
+                };
+                let config = props.config;
                 view! {
                     <input
-                        type=num_impl!(@type $($($type)?)?)
+                        type=input_type
                         class={class}
                         id={props.id.or_else(|| props.name.clone())}
-                        max=num_impl!(@max $ty $($(, $max)?)?)
-                        min=num_impl!(@min $ty $($(, $min)?)?)
+                        max=max
+                        min=min
                         name={props.name}
-                        on:keydown=num_impl!(@prevent_invalid_keystrokes value $($($type)?)?)
+                        required=required
+                        pattern=props.input_attrs.pattern
+                        step=step
+                        inputmode=props.input_attrs.input_mode.or(Some(Oco::Borrowed(input_mode)))
+                        autocomplete=props.input_attrs.autocomplete
+                        on:keydown=move |ev: web_sys::KeyboardEvent| if !is_localized {
+                            (num_impl!(@prevent_invalid_keystrokes value $($($type)?)?))(ev)
+                        }
                         on:input=move |ev| {
                             let target_value = ev.target().unwrap().unchecked_into::<web_sys::HtmlInputElement>().value();
-                            props.signal.value.update(|value| *value = target_value)
+                            props.signal.value.update(|value| *value = target_value);
+                            events.handle(ValidateOn::Input)
                         }
                         on:change=move |_| {
-                            if !props.is_optional || !<Self as FormField<HtmlElement<Input, (), ()>>>::is_default_value(&props.signal) {
-                                if let Err(form_error) = <Self as FormField<HtmlElement<Input, (), ()>>>::try_from_signal(props.signal, &props.config) {
-                                    props.signal.error.update(|error| *error = Some(form_error));
-                                } else if props.signal.error.with_untracked(|error| error.is_some()) {
-                                    props.signal.error.update(|error| *error = None);
+                            if is_localized {
+                                if let Some(text) = props.signal.value.with_untracked(|value| config.reformat(value)) {
+                                    props.signal.value.set(text);
                                 }
-                            } else {
-                                props.signal.error.update(|error| *error = None);
                             }
+                            events.handle(ValidateOn::Change)
                         }
+                        on:focusout=move |_| events.handle(ValidateOn::Blur)
                         prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(&*x)))}
                         prop:value={props.signal.value}
                         style={props.style}
@@ -110,17 +485,84 @@ macro_rules! num_impl {
                 }
             }
         }
+
+        // sliders share the parsing and bounds of the numeric input
+        impl FormField<Slider> for $ty {
+            type Config = SliderConfig<$ty>;
+            type Signal = FormFieldSignal<String>;
+
+            fn default_signal(config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                <Self as FormField<NumInput>>::default_signal(&config.num, initial)
+            }
+            // a slider holds no value while it sits at its default value, see `SliderConfig::start`
+            fn is_default_value(signal: &Self::Signal) -> bool {
+                <Self as FormField<NumInput>>::is_default_value(signal)
+            }
+            fn into_signal(self, config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                <Self as FormField<NumInput>>::into_signal(self, &config.num, initial)
+            }
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                if !signal.value.with_untracked(|value| value.is_empty()) {
+                    return <Self as FormField<NumInput>>::try_from_signal(signal, &config.num);
+                }
+                let value = config.start();
+                crate::validation::range(&value, config.num.min.clone(), config.num.max.clone())?;
+                Ok(value)
+            }
+            fn recurse(signal: &Self::Signal) {
+                <Self as FormField<NumInput>>::recurse(signal)
+            }
+            fn reset_initial_value(signal: &Self::Signal) {
+                <Self as FormField<NumInput>>::reset_initial_value(signal)
+            }
+            fn has_changed(signal: &Self::Signal) -> bool {
+                <Self as FormField<NumInput>>::has_changed(signal)
+            }
+            fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
+                <Self as FormField<NumInput>>::with_error(signal, f)
+            }
+            fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+                <Self as FormField<NumInput>>::set_error(signal, error)
+            }
+            fn is_pending(signal: &Self::Signal) -> bool {
+                <Self as FormField<NumInput>>::is_pending(signal)
+            }
+            fn set_pending(signal: &Self::Signal, pending: bool) {
+                <Self as FormField<NumInput>>::set_pending(signal, pending)
+            }
+            fn is_touched(signal: &Self::Signal) -> bool {
+                <Self as FormField<NumInput>>::is_touched(signal)
+            }
+            fn touch(signal: &Self::Signal) {
+                <Self as FormField<NumInput>>::touch(signal)
+            }
+        }
+
+        impl FormComponent<Slider> for $ty {
+            fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+                Slider::render::<Self>(props)
+            }
+        }
     )* };
     (@type $type:literal) => {$type};
     (@type) => {"number"};
 
+    (@input_mode $type:literal) => {"decimal"};
+    (@input_mode) => {"numeric"};
+
     (@min $ty:ty, $min:expr) => {$min};
     (@min $ty:ty) => {<$ty>::MIN};
 
     (@max $ty:ty, $max:expr) => {$max};
     (@max $ty:ty) => {<$ty>::MAX};
 
-    (@prevent_invalid_keystrokes $value:ident $type:literal) => {|ev| {
+    (@min_attr $ty:ty, $min:expr) => {Option::<&str>::from($min).map(str::to_string)};
+    (@min_attr $ty:ty) => {Some(<$ty>::MIN.to_string())};
+
+    (@max_attr $ty:ty, $max:expr) => {Option::<&str>::from($max).map(str::to_string)};
+    (@max_attr $ty:ty) => {Some(<$ty>::MAX.to_string())};
+
+    (@prevent_invalid_keystrokes $value:ident $type:literal) => {|ev: web_sys::KeyboardEvent| {
         let key = ev.key();
         if key.len() > 1 {
             return;
@@ -131,7 +573,7 @@ macro_rules! num_impl {
             }
         }
     }};
-    (@prevent_invalid_keystrokes $value:ident) => {|_| {}};
+    (@prevent_invalid_keystrokes $value:ident) => {|_: web_sys::KeyboardEvent| {}};
 }
 
 num_impl!(
@@ -167,3 +609,404 @@ num_impl!(
 num_impl!(
     bigdecimal::BigDecimal { type: "text", min: None::<&'static str>, max: None::<&'static str> },
 );
+
+/// Accessors of the text of a slider field, which holds no value while the slider sits at its plain `start` value:
+/// the plain number of the slider's inputs, a setter taking a plain number and the displayed text of the value.
+fn slider_text<T: FromStr + ToString + Send + Sync + 'static>(
+    value: RwSignal<String>,
+    num: StoredValue<NumConfig<T>>,
+    start: StoredValue<String>,
+) -> (
+    impl Fn() -> String + Copy + Send + Sync + 'static,
+    impl Fn(String) + Copy + Send + Sync + 'static,
+    impl Fn() -> String + Copy + Send + Sync + 'static,
+) {
+    let plain = move || {
+        value.with(|value| match value.is_empty() {
+            true => start.get_value(),
+            false => num.with_value(|num| num.normalize(value)),
+        })
+    };
+    let set_plain = move |plain: String| {
+        let is_start = plain
+            .parse::<T>()
+            .is_ok_and(|x| start.with_value(|start| x.to_string() == *start));
+        value.set(match is_start {
+            true => String::new(),
+            false => num.with_value(|num| num.localize(plain)),
+        });
+    };
+    let displayed = move || match value.with(|value| value.is_empty()) {
+        true => num.with_value(|num| num.localize(start.get_value())),
+        false => value.get(),
+    };
+    (plain, set_plain, displayed)
+}
+
+#[cfg(feature = "thaw")]
+impl Slider {
+    fn render<T>(props: RenderProps<FormFieldSignal<String>, SliderConfig<T>>) -> impl IntoView
+    where
+        T: FormField<Slider, Signal = FormFieldSignal<String>, Config = SliderConfig<T>>
+            + Clone
+            + Default
+            + FromStr
+            + ToString
+            + Send
+            + Sync
+            + 'static,
+    {
+        let class = props.class_signal();
+        let events = FieldEvents::<T, Slider>::new(&props);
+        let value = props.signal.value;
+        let start = StoredValue::new(props.config.start().to_string());
+        let SliderConfig {
+            num,
+            readout,
+            number_box,
+            ..
+        } = props.config;
+        let bound = |bound: Option<&T>| bound.and_then(|bound| bound.to_string().parse::<f64>().ok());
+        let (min, max) = (bound(num.min.as_ref()), bound(num.max.as_ref()));
+        let step = num.step_attr().and_then(|step| step.parse::<f64>().ok());
+        let num = StoredValue::new(num);
+
+        let (plain, set_plain, displayed) = slider_text(value, num, start);
+
+        // the slider holds the value as a float while the field keeps the displayed text
+        let number = RwSignal::new(untrack(plain).parse::<f64>().unwrap_or_default());
+        Effect::watch(
+            move || number.get(),
+            move |number, _, _| {
+                set_plain(number.to_string());
+                events.handle(ValidateOn::Input);
+                events.handle(ValidateOn::Change);
+            },
+            false,
+        );
+
+        view! {
+            <div style="display: flex; flex-direction: row; align-items: center; gap: 0.5rem;">
+                <thaw::Slider
+                    class={class.get().map(|s| s.to_string())}
+                    id={props.id.map(|s| s.to_string()).or_else(|| props.name.clone().map(|s| s.to_string()))}
+                    name={props.name.map(|s| s.to_string())}
+                    min=min.unwrap_or(0.)
+                    max=max.unwrap_or(100.)
+                    step=step
+                    on:focusout=move |_| events.handle(ValidateOn::Blur)
+                    value=number
+                />
+                {readout.then(|| view! { <output>{displayed}</output> })}
+                {number_box.then(|| view! {
+                    <thaw::SpinButton<f64>
+                        min=min.unwrap_or(f64::MIN)
+                        max=max.unwrap_or(f64::MAX)
+                        step_page=step.unwrap_or(1.)
+                        value=number
+                    />
+                })}
+            </div>
+        }
+    }
+}
+
+#[cfg(not(feature = "thaw"))]
+impl Slider {
+    fn render<T>(props: RenderProps<FormFieldSignal<String>, SliderConfig<T>>) -> impl IntoView
+    where
+        T: FormField<Slider, Signal = FormFieldSignal<String>, Config = SliderConfig<T>>
+            + Clone
+            + Default
+            + FromStr
+            + ToString
+            + Send
+            + Sync
+            + 'static,
+    {
+        let class = props.class_signal();
+        let events = FieldEvents::<T, Slider>::new(&props);
+        let value = props.signal.value;
+        let id = props.id.or_else(|| props.name.clone());
+        let start = StoredValue::new(props.config.start().to_string());
+        let SliderConfig {
+            num,
+            readout,
+            number_box,
+            ..
+        } = props.config;
+        let min = num.min.as_ref().map(|min| min.to_string());
+        let max = num.max.as_ref().map(|max| max.to_string());
+        let step = num.step_attr().or(props.input_attrs.step);
+        let num = StoredValue::new(num);
+
+        // the range and number inputs hold the plain number while the field keeps the displayed text
+        let (plain, set_plain, displayed) = slider_text(value, num, start);
+        let on_input = move |ev: web_sys::Event| {
+            set_plain(event_target_value(&ev));
+            events.handle(ValidateOn::Input)
+        };
+
+        view! {
+            <div style="display: flex; flex-direction: row; align-items: center; gap: 0.5rem;">
+                <input
+                    type="range"
+                    class={class}
+                    id={id.clone()}
+                    min=min.clone()
+                    max=max.clone()
+                    step=step.clone()
+                    name={props.name}
+                    on:input=on_input
+                    on:change=move |_| events.handle(ValidateOn::Change)
+                    on:focusout=move |_| events.handle(ValidateOn::Blur)
+                    prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                    prop:value=plain
+                    style={props.style}
+                    value=plain
+                />
+                {readout.then(|| view! { <output for=id>{displayed}</output> })}
+                {number_box.then(|| view! {
+                    <input
+                        type="number"
+                        min=min
+                        max=max
+                        step=step
+                        inputmode="decimal"
+                        on:input=on_input
+                        on:change=move |_| events.handle(ValidateOn::Change)
+                        on:focusout=move |_| events.handle(ValidateOn::Blur)
+                        prop:value=plain
+                    />
+                })}
+            </div>
+        }
+    }
+}
+
+macro_rules! nonzero_impl {
+    ($($ty:ident($int:ty) $({ min: $min:literal })?),*$(,)?) => { $(
+        impl DefaultHtmlElement for $ty {
+            type El = HtmlElement<Input, (), ()>;
+        }
+
+        impl FormField<HtmlElement<Input, (), ()>> for $ty {
+            type Config = NumConfig<$ty>;
+            type Signal = FormFieldSignal<String>;
+
+            fn default_signal(config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                FormFieldSignal::new_with_default_value(initial.map(|x| config.localize(x.to_string())))
+            }
+            fn is_default_value(signal: &Self::Signal) -> bool {
+                signal.value.with_untracked(|value| value.is_empty())
+            }
+            fn into_signal(self, config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                FormFieldSignal::new(
+                    config.localize(self.to_string()),
+                    initial.map(|x| config.localize(x.to_string())),
+                )
+            }
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                let value = signal.value.with_untracked(|value| config.normalize(value).parse::<$int>()).map_err(FormError::parse)?;
+                let value = $ty::new(value).ok_or_else(|| FormError::validation("must not be zero").with_code("non_zero"))?;
+                crate::validation::range(&value, config.min, config.max)?;
+                Ok(value)
+            }
+            fn recurse(signal: &Self::Signal) {
+                signal.value.with_untracked(|_| {})
+            }
+            fn reset_initial_value(signal: &Self::Signal) {
+                signal.value.with_untracked(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
+            }
+            fn has_changed(signal: &Self::Signal) -> bool {
+                signal.has_changed_tracked()
+            }
+            fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
+                signal.error.with(|error| f(error.as_ref()))
+            }
+            fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+                signal.error.set(error)
+            }
+            fn is_pending(signal: &Self::Signal) -> bool {
+                signal.pending.get()
+            }
+            fn set_pending(signal: &Self::Signal, pending: bool) {
+                signal.pending.set(pending)
+            }
+            fn is_touched(signal: &Self::Signal) -> bool {
+                signal.touched.get()
+            }
+            fn touch(signal: &Self::Signal) {
+                signal.touched.set(true)
+            }
+        }
+
+        impl FormComponent<HtmlElement<Input, (), ()>> for $ty {
+            fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+                let class = props.class_signal();
+                let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
+                let required = props.is_required();
+                let step = props.config.step_attr().or(props.input_attrs.step);
+                let min = props.config.min.map(|min| min.to_string()) $(.or(Some($min.to_string())))?;
+                let max = props.config.max.map(|max| max.to_string());
+                let is_localized = props.config.is_localized();
+                let config = props.config;
+                view! {
+                    <input
+                        type=if is_localized { "text" } else { "number" }
+                        class={class}
+                        id={props.id.or_else(|| props.name.clone())}
+                        min=min
+                        max=max
+                        name={props.name}
+                        required=required
+                        step=step
+                        inputmode=props.input_attrs.input_mode.or(Some(Oco::Borrowed("numeric")))
+                        autocomplete=props.input_attrs.autocomplete
+                        on:input=move |ev| {
+                            props.signal.value.update(|value| *value = event_target_value(&ev));
+                            events.handle(ValidateOn::Input)
+                        }
+                        on:change=move |_| {
+                            if is_localized {
+                                if let Some(text) = props.signal.value.with_untracked(|value| config.reformat(value)) {
+                                    props.signal.value.set(text);
+                                }
+                            }
+                            events.handle(ValidateOn::Change)
+                        }
+                        on:focusout=move |_| events.handle(ValidateOn::Blur)
+                        prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                        prop:value={props.signal.value}
+                        style={props.style}
+                        value=props.signal.value
+                    />
+                }
+            }
+        }
+    )* };
+}
+
+nonzero_impl!(
+    NonZeroU8(u8) { min: 1 },
+    NonZeroU16(u16) { min: 1 },
+    NonZeroU32(u32) { min: 1 },
+    NonZeroU64(u64) { min: 1 },
+    NonZeroU128(u128) { min: 1 },
+    NonZeroUsize(usize) { min: 1 },
+    NonZeroI8(i8),
+    NonZeroI16(i16),
+    NonZeroI32(i32),
+    NonZeroI64(i64),
+    NonZeroI128(i128),
+    NonZeroIsize(isize),
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn step_defaults_to_the_last_allowed_decimal_place() {
+        let step = |config: NumConfig<f64>| config.step_attr().map(|step| step.to_string());
+        assert_eq!(None, step(NumConfig::default()));
+        assert_eq!(Some("1".to_string()), step(NumConfig::builder().precision(0).build()));
+        assert_eq!(
+            Some("0.01".to_string()),
+            step(NumConfig::builder().precision(2).build())
+        );
+        assert_eq!(
+            Some("0.5".to_string()),
+            step(NumConfig::builder().precision(2).step(0.5).build())
+        );
+    }
+
+    #[test]
+    fn excess_precision_is_rejected() {
+        let config = NumConfig::<f64>::builder().precision(2).build();
+        assert!(!config.check_precision("1").unwrap());
+        assert!(!config.check_precision("-1.25").unwrap());
+        assert!(!config.check_precision("1.5e3").unwrap());
+        let err = config.check_precision("1.255").unwrap_err();
+        assert!(err.is_validation());
+        assert_eq!("must have at most 2 decimal places", err.to_string());
+        assert!(!NumConfig::<f64>::default().check_precision("1.255").unwrap());
+    }
+
+    #[test]
+    fn excess_precision_is_rounded() {
+        let config = NumConfig::<f64>::builder()
+            .precision(0)
+            .excess_precision(ExcessPrecision::Round)
+            .build();
+        assert!(config.check_precision("2.5").unwrap());
+        assert!(!config.check_precision("2").unwrap());
+        assert_eq!(1.23, 1.2349_f64.round_to(2));
+        assert_eq!(-1.5, (-1.4999_f64).round_to(1));
+        assert_eq!(3, 3_u8.round_to(2));
+    }
+
+    #[test]
+    fn locales_format_plain_numbers() {
+        assert_eq!("1,234,567.89", NumLocale::EN.format("1234567.89"));
+        assert_eq!("-1.234,5", NumLocale::DE.format("-1234.5"));
+        assert_eq!("1\u{202f}000", NumLocale::FR.format("1000"));
+        assert_eq!("999", NumLocale::CH.format("999"));
+        assert_eq!("0,5", NumLocale::DE.format("0.5"));
+        assert_eq!("1234", NumLocale::new('.', None).format("+1234"));
+        assert_eq!("1e10", NumLocale::EN.format("1e10"));
+        assert_eq!("NaN", NumLocale::EN.format("NaN"));
+    }
+
+    #[test]
+    fn locales_normalize_displayed_text() {
+        assert_eq!("1234567.89", NumLocale::EN.normalize("1,234,567.89"));
+        assert_eq!("-1234.5", NumLocale::DE.normalize("-1.234,5"));
+        assert_eq!("1000.5", NumLocale::FR.normalize("1\u{202f}000,5"));
+        assert_eq!("1000", NumLocale::FR.normalize(" 1 000 "));
+        assert_eq!("1234.5", NumLocale::CH.normalize("1'234.5"));
+    }
+
+    #[test]
+    fn decimal_point_is_shifted() {
+        assert_eq!("12.5", shift_decimal_point("0.125", 2));
+        assert_eq!("100", shift_decimal_point("1", 2));
+        assert_eq!("-50", shift_decimal_point("-0.5", 2));
+        assert_eq!("0.125", shift_decimal_point("12.5", -2));
+        assert_eq!("0.00001", shift_decimal_point("0.001", -2));
+        assert_eq!("-0.005", shift_decimal_point("-0.5", -2));
+        assert_eq!("0", shift_decimal_point("0", -2));
+        assert_eq!("1e3", shift_decimal_point("1e3", 2));
+        assert_eq!("1.2.3", shift_decimal_point("1.2.3", 2));
+        assert_eq!("", shift_decimal_point("", 2));
+    }
+
+    #[test]
+    fn percentages_round_trip() {
+        let config = NumConfig::<f64>::builder()
+            .locale(NumLocale::DE)
+            .presentation(NumPresentation::Percent)
+            .build();
+        assert_eq!("12,5%", config.localize("0.125".to_string()));
+        assert_eq!("-1.250%", config.localize("-12.5".to_string()));
+        assert_eq!("0.125", config.normalize(" 12,5 % "));
+        assert_eq!("-12.5", config.normalize("-1.250%"));
+    }
+
+    #[test]
+    fn currencies_round_trip() {
+        let prefix = NumConfig::<f64>::builder()
+            .locale(NumLocale::EN)
+            .presentation(NumPresentation::Currency(Oco::Borrowed("$")))
+            .build();
+        assert_eq!("$1,234.56", prefix.localize("1234.56".to_string()));
+        assert_eq!("1234.56", prefix.normalize("$1,234.56"));
+        let suffix = NumConfig::<f64>::builder()
+            .locale(NumLocale::DE)
+            .presentation(NumPresentation::CurrencySuffix(Oco::Borrowed("€")))
+            .build();
+        assert_eq!("1.234,56 €", suffix.localize("1234.56".to_string()));
+        assert_eq!("1234.56", suffix.normalize("1.234,56 €"));
+        assert_eq!("abc", suffix.normalize("abc"));
+    }
+}
@@ -0,0 +1,279 @@
+use crate::*;
+use ::leptos::html::*;
+use ::leptos::prelude::*;
+use ::std::fmt;
+use ::wasm_bindgen::JsValue;
+
+/// A password or any other text which must not be leaked by the form.
+///
+/// A secret is rendered as a password input, is never written to a draft cache (see [`FormField::redact`]),
+/// is redacted from its [`Debug`] output and is zeroed when dropped or when the form is reset.
+#[derive(Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Secret(String);
+
+/// Configuration for [`Secret`] fields.
+#[derive(Clone, Copy, Debug, Derivative, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[derivative(Default)]
+pub struct SecretConfig {
+    /// display a button showing or hiding the secret
+    #[builder(default = true)]
+    #[derivative(Default(value = "true"))]
+    pub toggle: bool,
+    /// display an estimate of the strength of the secret below its input
+    pub strength_meter: bool,
+    /// weakest accepted secret
+    #[builder(setter(strip_option))]
+    pub min_strength: Option<PasswordStrength>,
+}
+
+/// A rough estimate of how hard a password is to guess, based on its length and the kinds of characters it contains.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PasswordStrength {
+    VeryWeak,
+    Weak,
+    Fair,
+    Strong,
+    VeryStrong,
+}
+
+impl Secret {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(secret.into())
+    }
+
+    /// The secret in plain text.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Secret").field(&"********").finish()
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        zeroize(&mut self.0)
+    }
+}
+
+impl From<String> for Secret {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+}
+
+impl crate::validation::Required for Secret {
+    fn is_present(&self) -> bool {
+        !self.expose().is_empty()
+    }
+}
+
+impl crate::validation::Length for Secret {
+    const UNIT: &'static str = "characters";
+    fn length(&self) -> Option<usize> {
+        Some(self.expose().chars().count())
+    }
+}
+
+impl crate::validation::Text for Secret {
+    fn text(&self) -> Option<&str> {
+        Some(self.expose())
+    }
+}
+
+impl PasswordStrength {
+    pub fn of(password: &str) -> Self {
+        let length = password.chars().count();
+        let classes = [
+            char::is_lowercase as fn(char) -> bool,
+            char::is_uppercase,
+            |c| c.is_ascii_digit(),
+            |c| !c.is_alphanumeric(),
+        ]
+        .into_iter()
+        .filter(|class| password.chars().any(class))
+        .count();
+        let score = match length {
+            0 => 0,
+            1..=7 => classes.min(2).saturating_sub(1),
+            8..=11 => classes,
+            12..=15 => classes + 1,
+            _ => classes + 2,
+        };
+        match score {
+            0 => Self::VeryWeak,
+            1 => Self::Weak,
+            2 => Self::Fair,
+            3 => Self::Strong,
+            _ => Self::VeryStrong,
+        }
+    }
+
+    pub fn score(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::VeryWeak => "very weak",
+            Self::Weak => "weak",
+            Self::Fair => "fair",
+            Self::Strong => "strong",
+            Self::VeryStrong => "very strong",
+        }
+    }
+}
+
+/// Overwrites the bytes of `value` in place before clearing it.
+fn zeroize(value: &mut String) {
+    let len = value.len();
+    value.replace_range(.., &"\0".repeat(len));
+    value.clear();
+}
+
+impl DefaultHtmlElement for Secret {
+    type El = HtmlElement<Input, (), ()>;
+}
+
+impl FormField<HtmlElement<Input, (), ()>> for Secret {
+    type Config = SecretConfig;
+    type Signal = FormFieldSignal<String>;
+
+    fn default_signal(_: &Self::Config, initial: Option<Self>) -> Self::Signal {
+        FormFieldSignal::new_with_default_value(initial.map(|mut x| ::std::mem::take(&mut x.0)))
+    }
+    fn is_default_value(signal: &Self::Signal) -> bool {
+        signal.value.with_untracked(|value| value.is_empty())
+    }
+    fn into_signal(mut self, _: &Self::Config, initial: Option<Self>) -> Self::Signal {
+        FormFieldSignal::new(
+            ::std::mem::take(&mut self.0),
+            initial.map(|mut x| ::std::mem::take(&mut x.0)),
+        )
+    }
+    fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+        signal.value.with_untracked(|value| {
+            if let Some(min_strength) = config.min_strength {
+                if !value.is_empty() && PasswordStrength::of(value) < min_strength {
+                    return Err(
+                        FormError::validation(format!("is too weak, must be {}", min_strength.label()))
+                            .with_code("strength")
+                            .with_param("min", min_strength.label()),
+                    );
+                }
+            }
+            Ok(Self(value.clone()))
+        })
+    }
+    fn recurse(signal: &Self::Signal) {
+        signal.value.with_untracked(|_| {})
+    }
+    fn reset_initial_value(signal: &Self::Signal) {
+        // the secret is zeroed rather than kept as the initial value, emptying the input
+        signal.value.update(zeroize);
+        signal.initial.update(|initial| {
+            if let Some(initial) = initial {
+                zeroize(initial);
+            }
+            *initial = Some(String::new());
+        });
+    }
+    fn has_changed(signal: &Self::Signal) -> bool {
+        signal.has_changed_tracked()
+    }
+    fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
+        signal.error.with(|error| f(error.as_ref()))
+    }
+    fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+        signal.error.set(error)
+    }
+    fn is_pending(signal: &Self::Signal) -> bool {
+        signal.pending.get()
+    }
+    fn set_pending(signal: &Self::Signal, pending: bool) {
+        signal.pending.set(pending)
+    }
+    fn is_touched(signal: &Self::Signal) -> bool {
+        signal.touched.get()
+    }
+    fn touch(signal: &Self::Signal) {
+        signal.touched.set(true)
+    }
+    fn redact(&mut self) {
+        zeroize(&mut self.0)
+    }
+}
+
+impl FormComponent<HtmlElement<Input, (), ()>> for Secret {
+    fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+        let class = props.class_signal();
+        let events = FieldEvents::<Self, HtmlElement<Input, (), ()>>::new(&props);
+        let value = props.signal.value;
+        let visible = RwSignal::new(false);
+        let strength = move || value.with(|value| PasswordStrength::of(value));
+
+        // the secret is only ever set as a property: as an attribute it would end up in server rendered html
+        view! {
+            <div style="display: flex; flex-direction: column; gap: 0.25rem;">
+                <div style="display: flex; flex-direction: row; gap: 0.5rem;">
+                    <input
+                        type=move || match visible.get() {
+                            true => "text",
+                            false => "password",
+                        }
+                        class={class}
+                        id={props.id.or_else(|| props.name.clone())}
+                        name={props.name}
+                        required=props.input_attrs.required
+                        minlength=props.input_attrs.min_length.map(|x| x.to_string())
+                        maxlength=props.input_attrs.max_length.map(|x| x.to_string())
+                        pattern=props.input_attrs.pattern
+                        autocomplete=props.input_attrs.autocomplete.or(Some(Oco::Borrowed("current-password")))
+                        on:input=move |ev| {
+                            value.update(|value| *value = event_target_value(&ev));
+                            events.handle(ValidateOn::Input)
+                        }
+                        on:change=move |_| events.handle(ValidateOn::Change)
+                        on:focusout=move |_| events.handle(ValidateOn::Blur)
+                        prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                        prop:value=value
+                        style={props.style}
+                    />
+                    {props.config.toggle.then(|| view! {
+                        <button
+                            type="button"
+                            aria-pressed=move || visible.get().to_string()
+                            on:click=move |_| visible.update(|visible| *visible = !*visible)
+                        >
+                            {move || match visible.get() {
+                                true => "Hide",
+                                false => "Show",
+                            }}
+                        </button>
+                    })}
+                </div>
+                {props.config.strength_meter.then(|| view! {
+                    <div style="display: flex; flex-direction: row; gap: 0.5rem; align-items: center;">
+                        <meter min="0" max="4" low="2" high="3" optimum="4" value=move || strength().score().to_string() />
+                        <span>{move || strength().label()}</span>
+                    </div>
+                })}
+            </div>
+        }
+    }
+}
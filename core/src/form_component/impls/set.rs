@@ -0,0 +1,292 @@
+use super::choice::{Choice, ChoiceOptions};
+use super::config::collections::VecConfigSize;
+use crate::*;
+use ::indexmap::IndexSet;
+use ::leptos::html::*;
+use ::leptos::prelude::*;
+use ::std::collections::{BTreeSet, HashSet};
+use ::std::hash::Hash;
+use ::wasm_bindgen::{JsCast, JsValue};
+
+/// Element type rendering a set as a list of checkboxes, one per option of its [`SetConfig`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CheckboxList;
+
+/// Configuration for a set of [`Choice`] values.
+#[derive(Clone, Derivative, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[derivative(Debug, Default(bound = ""))]
+pub struct SetConfig<T: Send + Sync + 'static> {
+    /// the options which can be selected
+    pub options: ChoiceOptions<T>,
+    /// bounds on the number of selected options
+    pub size: VecConfigSize,
+}
+
+impl<T: Send + Sync + 'static> SetConfig<T> {
+    /// Checks the number of selected options against [`SetConfig::size`].
+    fn check_size(&self, num_selected: usize) -> Result<(), FormError> {
+        let (min, max) = self.size.split();
+        if let Some(min) = min.filter(|min| num_selected < *min) {
+            return Err(FormError::validation(format!("must select at least {min} options"))
+                .with_code("min_len")
+                .with_param("min", min));
+        }
+        if let Some(max) = max.filter(|max| num_selected > *max) {
+            return Err(FormError::validation(format!("must select at most {max} options"))
+                .with_code("max_len")
+                .with_param("max", max));
+        }
+        Ok(())
+    }
+}
+
+macro_rules! set_impl {
+    ($($set:ident<T: $($bound:path),+>),*$(,)?) => { $(
+        impl<T> DefaultHtmlElement for $set<T> {
+            type El = CheckboxList;
+        }
+
+        set_impl! { @ $set<T: $($bound),+>, CheckboxList, CheckboxList }
+        set_impl! { @ $set<T: $($bound),+>, HtmlElement<Select, (), ()>, MultiSelect }
+    )* };
+
+    (@ $set:ident<T: $($bound:path),+>, $el:ty, $render:ident) => {
+        // the signal holds the values of the selected options in the order they were selected,
+        // `IndexSet` equality ignores that order so reselecting an initial option is not a change
+        impl<T: Choice $(+ $bound)+> FormField<$el> for $set<T> {
+            type Config = SetConfig<T>;
+            type Signal = FormFieldSignal<IndexSet<String>>;
+
+            fn default_signal(_: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                FormFieldSignal::new_with_default_value(initial.map(|x| x.iter().map(Choice::to_choice).collect()))
+            }
+            fn is_default_value(signal: &Self::Signal) -> bool {
+                signal.value.with_untracked(|value| value.is_empty())
+            }
+            fn into_signal(self, _: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                FormFieldSignal::new(
+                    self.iter().map(Choice::to_choice).collect(),
+                    initial.map(|x| x.iter().map(Choice::to_choice).collect()),
+                )
+            }
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                signal.value.with_untracked(|value| {
+                    config.check_size(value.len())?;
+                    value.iter().map(|value| T::from_choice(value)).collect()
+                })
+            }
+            fn recurse(signal: &Self::Signal) {
+                signal.value.with_untracked(|_| {})
+            }
+            fn reset_initial_value(signal: &Self::Signal) {
+                signal.value.with_untracked(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
+            }
+            fn has_changed(signal: &Self::Signal) -> bool {
+                signal.has_changed_tracked()
+            }
+            fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
+                signal.error.with(|error| f(error.as_ref()))
+            }
+            fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+                signal.error.set(error)
+            }
+            fn is_pending(signal: &Self::Signal) -> bool {
+                signal.pending.get()
+            }
+            fn set_pending(signal: &Self::Signal, pending: bool) {
+                signal.pending.set(pending)
+            }
+            fn is_touched(signal: &Self::Signal) -> bool {
+                signal.touched.get()
+            }
+            fn touch(signal: &Self::Signal) {
+                signal.touched.set(true)
+            }
+        }
+
+        impl<T: Choice $(+ $bound)+> FormComponent<$el> for $set<T> {
+            fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+                $render::render::<Self, T>(props)
+            }
+        }
+    };
+}
+
+set_impl!(
+    HashSet<T: Eq, Hash>,
+    BTreeSet<T: Ord>,
+    IndexSet<T: Eq, Hash>,
+);
+
+/// Moves the selection to `selected`, keeping the order in which still selected options were selected.
+fn set_selection(value: RwSignal<IndexSet<String>>, selected: impl IntoIterator<Item = String>) {
+    let selected = selected.into_iter().collect::<IndexSet<_>>();
+    value.update(|value| {
+        value.retain(|option| selected.contains(option));
+        value.extend(selected);
+    });
+}
+
+#[cfg(feature = "thaw")]
+impl CheckboxList {
+    fn render<S, T: Choice>(props: RenderProps<FormFieldSignal<IndexSet<String>>, SetConfig<T>>) -> impl IntoView
+    where
+        S: FormField<CheckboxList, Signal = FormFieldSignal<IndexSet<String>>, Config = SetConfig<T>> + 'static,
+    {
+        let class = props.class_signal();
+        let events = FieldEvents::<S, CheckboxList>::new(&props);
+        let value = props.signal.value;
+        let options = props.config.options.clone();
+
+        let checked = RwSignal::new(value.with_untracked(|value| value.iter().cloned().collect::<HashSet<_>>()));
+        Effect::new(move |_| {
+            let selected = value.with(|value| value.iter().cloned().collect::<HashSet<_>>());
+            if checked.with_untracked(|checked| *checked != selected) {
+                checked.set(selected);
+            }
+        });
+        Effect::watch(
+            move || checked.get(),
+            move |checked, _, _| {
+                if value
+                    .with_untracked(|value| value.len() != checked.len() || value.iter().any(|x| !checked.contains(x)))
+                {
+                    set_selection(value, checked.iter().cloned());
+                    events.handle(ValidateOn::Input);
+                    events.handle(ValidateOn::Change);
+                }
+            },
+            false,
+        );
+
+        view! {
+            <thaw::CheckboxGroup
+                class={class.get().map(|s| s.to_string())}
+                id={props.id.map(|s| s.to_string()).or_else(|| props.name.clone().map(|s| s.to_string()))}
+                name={props.name.map(|s| s.to_string())}
+                on:focusout=move |_| events.handle(ValidateOn::Blur)
+                value=checked
+            >
+                {move || options.with(|options| options.iter().map(|(option, label)| {
+                    view! { <thaw::Checkbox value=option.to_choice() label=label.to_string() /> }
+                }).collect_view())}
+            </thaw::CheckboxGroup>
+        }
+    }
+}
+
+#[cfg(not(feature = "thaw"))]
+impl CheckboxList {
+    fn render<S, T: Choice>(props: RenderProps<FormFieldSignal<IndexSet<String>>, SetConfig<T>>) -> impl IntoView
+    where
+        S: FormField<CheckboxList, Signal = FormFieldSignal<IndexSet<String>>, Config = SetConfig<T>> + 'static,
+    {
+        let class = props.class_signal();
+        let events = FieldEvents::<S, CheckboxList>::new(&props);
+        let value = props.signal.value;
+        let options = props.config.options.clone();
+        let (_, max) = props.config.size.split();
+        let name = props.name.clone();
+
+        view! {
+            <div
+                role="group"
+                class={class}
+                id={props.id.or(props.name)}
+                prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                style={props.style}
+            >
+                {move || options.with(|options| options.iter().map(|(option, label)| {
+                    let option = option.to_choice();
+                    let is_checked = {
+                        let option = option.clone();
+                        move || value.with(|value| value.contains(&option))
+                    };
+                    // once the maximum number of options is selected, the remaining ones can no longer be checked
+                    let is_disabled = {
+                        let option = option.clone();
+                        move || max.is_some_and(|max| value.with(|value| value.len() >= max && !value.contains(&option)))
+                    };
+                    let toggled = option.clone();
+                    view! {
+                        <label>
+                            <input
+                                type="checkbox"
+                                name={name.clone()}
+                                value=option
+                                disabled=is_disabled
+                                on:input=move |ev| {
+                                    let is_checked = event_target_checked(&ev);
+                                    value.update(|value| match is_checked {
+                                        true => {
+                                            value.insert(toggled.clone());
+                                        }
+                                        false => {
+                                            value.shift_remove(&toggled);
+                                        }
+                                    });
+                                    events.handle(ValidateOn::Input)
+                                }
+                                on:change=move |_| events.handle(ValidateOn::Change)
+                                on:focusout=move |_| events.handle(ValidateOn::Blur)
+                                prop:checked=is_checked
+                            />
+                            {label.to_string()}
+                        </label>
+                    }
+                }).collect_view())}
+            </div>
+        }
+    }
+}
+
+/// Renders a set as a `<select multiple>`, used for the `HtmlElement<Select, (), ()>` element type.
+struct MultiSelect;
+
+impl MultiSelect {
+    fn render<S, T: Choice>(props: RenderProps<FormFieldSignal<IndexSet<String>>, SetConfig<T>>) -> impl IntoView
+    where
+        S: FormField<HtmlElement<Select, (), ()>, Signal = FormFieldSignal<IndexSet<String>>, Config = SetConfig<T>>
+            + 'static,
+    {
+        let class = props.class_signal();
+        let events = FieldEvents::<S, HtmlElement<Select, (), ()>>::new(&props);
+        let value = props.signal.value;
+        let options = props.config.options.clone();
+        let (min, _) = props.config.size.split();
+
+        view! {
+            <select
+                multiple
+                class={class}
+                id={props.id.or_else(|| props.name.clone())}
+                name={props.name}
+                required=props.input_attrs.required || min.is_some_and(|min| min > 0)
+                on:input=move |ev| {
+                    let selected = ev.target().unwrap().unchecked_into::<web_sys::HtmlSelectElement>().selected_options();
+                    set_selection(
+                        value,
+                        (0..selected.length())
+                            .filter_map(|i| selected.item(i))
+                            .map(|option| option.unchecked_into::<web_sys::HtmlOptionElement>().value()),
+                    );
+                    events.handle(ValidateOn::Input)
+                }
+                on:change=move |_| events.handle(ValidateOn::Change)
+                on:focusout=move |_| events.handle(ValidateOn::Blur)
+                prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(x)))}
+                style={props.style}
+            >
+                {move || options.with(|options| options.iter().map(|(option, label)| {
+                    let option = option.to_choice();
+                    view! {
+                        <option value=option.clone() selected=move || value.with(|value| value.contains(&option))>
+                            {label.to_string()}
+                        </option>
+                    }
+                }).collect_view())}
+            </select>
+        }
+    }
+}
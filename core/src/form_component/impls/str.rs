@@ -37,8 +37,26 @@ macro_rules! str_impl {
             fn reset_initial_value(signal: &Self::Signal) {
                 signal.value.with_untracked(|value| signal.initial.update(|initial| *initial = Some(value.clone())));
             }
+            fn has_changed(signal: &Self::Signal) -> bool {
+                signal.has_changed_tracked()
+            }
             fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
-                signal.error.with_untracked(|error| f(error.as_ref()))
+                signal.error.with(|error| f(error.as_ref()))
+            }
+            fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+                signal.error.set(error)
+            }
+            fn is_pending(signal: &Self::Signal) -> bool {
+                signal.pending.get()
+            }
+            fn set_pending(signal: &Self::Signal, pending: bool) {
+                signal.pending.set(pending)
+            }
+            fn is_touched(signal: &Self::Signal) -> bool {
+                signal.touched.get()
+            }
+            fn touch(signal: &Self::Signal) {
+                signal.touched.set(true)
             }
         }
 
@@ -46,6 +64,8 @@ macro_rules! str_impl {
         impl FormComponent<HtmlElement<$el, (), ()>> for $ty {
             fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
                 let class = props.class_signal();
+                let events = FieldEvents::<Self, HtmlElement<$el, (), ()>>::new(&props);
+                let required = props.is_required();
                 view! {
                     {
                         if stringify!($el) == "Textarea" {
@@ -54,21 +74,18 @@ macro_rules! str_impl {
                                     class={class.get().map(|s| s.to_string())}
                                     id={props.id.map(|s| s.to_string()).or_else(|| props.name.clone().map(|s| s.to_string()))}
                                     name={props.name.map(|s| s.to_string())}
+                                    attr:required=required
+                                    attr:minlength=props.input_attrs.min_length.map(|x| x.to_string())
+                                    attr:maxlength=props.input_attrs.max_length.map(|x| x.to_string())
+                                    attr:inputmode=props.input_attrs.input_mode
+                                    attr:autocomplete=props.input_attrs.autocomplete
                                     on:input=move |ev| {
                                         let target_value = ev.target().unwrap().unchecked_into::<web_sys::HtmlTextAreaElement>().value();
-                                        props.signal.value.update(|value| *value = target_value)
-                                    }
-                                    on:change=move |_| {
-                                        if !props.is_optional || !<Self as FormField<HtmlElement<$el, (), ()>>>::is_default_value(&props.signal) {
-                                            if let Err(form_error) = <Self as FormField<HtmlElement<$el, (), ()>>>::try_from_signal(props.signal, &props.config) {
-                                                props.signal.error.update(|error| *error = Some(form_error));
-                                            } else if props.signal.error.with_untracked(|error| error.is_some()) {
-                                                props.signal.error.update(|error| *error = None);
-                                            }
-                                        } else {
-                                            props.signal.error.update(|error| *error = None);
-                                        }
+                                        props.signal.value.update(|value| *value = target_value);
+                                        events.handle(ValidateOn::Input)
                                     }
+                                    on:change=move |_| events.handle(ValidateOn::Change)
+                                    on:focusout=move |_| events.handle(ValidateOn::Blur)
                                     prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(&*x)))}
                                     prop:value={props.signal.value}
                                     // style={props.style}
@@ -82,21 +99,19 @@ macro_rules! str_impl {
                                     class={class.get().map(|s| s.to_string())}
                                     id={props.id.map(|s| s.to_string()).or_else(|| props.name.clone().map(|s| s.to_string()))}
                                     name={props.name.map(|s| s.to_string())}
+                                    attr:required=required
+                                    attr:minlength=props.input_attrs.min_length.map(|x| x.to_string())
+                                    attr:maxlength=props.input_attrs.max_length.map(|x| x.to_string())
+                                    attr:pattern=props.input_attrs.pattern
+                                    attr:inputmode=props.input_attrs.input_mode
+                                    attr:autocomplete=props.input_attrs.autocomplete
                                     on:input=move |ev| {
                                         let target_value = ev.target().unwrap().unchecked_into::<web_sys::HtmlInputElement>().value();
-                                        props.signal.value.update(|value| *value = target_value)
-                                    }
-                                    on:change=move |_| {
-                                        if !props.is_optional || !<Self as FormField<HtmlElement<$el, (), ()>>>::is_default_value(&props.signal) {
-                                            if let Err(form_error) = <Self as FormField<HtmlElement<$el, (), ()>>>::try_from_signal(props.signal, &props.config) {
-                                                props.signal.error.update(|error| *error = Some(form_error));
-                                            } else if props.signal.error.with_untracked(|error| error.is_some()) {
-                                                props.signal.error.update(|error| *error = None);
-                                            }
-                                        } else {
-                                            props.signal.error.update(|error| *error = None);
-                                        }
+                                        props.signal.value.update(|value| *value = target_value);
+                                        events.handle(ValidateOn::Input)
                                     }
+                                    on:change=move |_| events.handle(ValidateOn::Change)
+                                    on:focusout=move |_| events.handle(ValidateOn::Blur)
                                     prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(&*x)))}
                                     prop:value={props.signal.value}
                                     input_style={props.style.map(|s| s.to_string())}
@@ -112,6 +127,8 @@ macro_rules! str_impl {
         impl FormComponent<HtmlElement<$el, (), ()>> for $ty {
             fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
                 let class = props.class_signal();
+                let events = FieldEvents::<Self, HtmlElement<$el, (), ()>>::new(&props);
+                let required = props.is_required();
                 view! {
                     {
                         if stringify!($el) == "Textarea" {
@@ -120,21 +137,18 @@ macro_rules! str_impl {
                                     class={class}
                                     id={props.id.or_else(|| props.name.clone())}
                                     name={props.name}
+                                    required=required
+                                    minlength=props.input_attrs.min_length.map(|x| x.to_string())
+                                    maxlength=props.input_attrs.max_length.map(|x| x.to_string())
+                                    inputmode=props.input_attrs.input_mode
+                                    autocomplete=props.input_attrs.autocomplete
                                     on:input=move |ev| {
                                         let target_value = ev.target().unwrap().unchecked_into::<web_sys::HtmlTextAreaElement>().value();
-                                        props.signal.value.update(|value| *value = target_value)
-                                    }
-                                    on:change=move |_| {
-                                        if !props.is_optional || !<Self as FormField<HtmlElement<$el, (), ()>>>::is_default_value(&props.signal) {
-                                            if let Err(form_error) = <Self as FormField<HtmlElement<$el, (), ()>>>::try_from_signal(props.signal, &props.config) {
-                                                props.signal.error.update(|error| *error = Some(form_error));
-                                            } else if props.signal.error.with_untracked(|error| error.is_some()) {
-                                                props.signal.error.update(|error| *error = None);
-                                            }
-                                        } else {
-                                            props.signal.error.update(|error| *error = None);
-                                        }
+                                        props.signal.value.update(|value| *value = target_value);
+                                        events.handle(ValidateOn::Input)
                                     }
+                                    on:change=move |_| events.handle(ValidateOn::Change)
+                                    on:focusout=move |_| events.handle(ValidateOn::Blur)
                                     prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(&*x)))}
                                     prop:value={props.signal.value}
                                     style={props.style}
@@ -149,21 +163,19 @@ macro_rules! str_impl {
                                     class={class}
                                     id={props.id.or_else(|| props.name.clone())}
                                     name={props.name}
+                                    required=required
+                                    minlength=props.input_attrs.min_length.map(|x| x.to_string())
+                                    maxlength=props.input_attrs.max_length.map(|x| x.to_string())
+                                    pattern=props.input_attrs.pattern
+                                    inputmode=props.input_attrs.input_mode
+                                    autocomplete=props.input_attrs.autocomplete
                                     on:input=move |ev| {
                                         let target_value = ev.target().unwrap().unchecked_into::<web_sys::HtmlInputElement>().value();
-                                        props.signal.value.update(|value| *value = target_value)
-                                    }
-                                    on:change=move |_| {
-                                        if !props.is_optional || !<Self as FormField<HtmlElement<$el, (), ()>>>::is_default_value(&props.signal) {
-                                            if let Err(form_error) = <Self as FormField<HtmlElement<$el, (), ()>>>::try_from_signal(props.signal, &props.config) {
-                                                props.signal.error.update(|error| *error = Some(form_error));
-                                            } else if props.signal.error.with_untracked(|error| error.is_some()) {
-                                                props.signal.error.update(|error| *error = None);
-                                            }
-                                        } else {
-                                            props.signal.error.update(|error| *error = None);
-                                        }
+                                        props.signal.value.update(|value| *value = target_value);
+                                        events.handle(ValidateOn::Input)
                                     }
+                                    on:change=move |_| events.handle(ValidateOn::Change)
+                                    on:focusout=move |_| events.handle(ValidateOn::Blur)
                                     prop:class={move || class.with_untracked(|x| x.as_ref().map(|x| JsValue::from_str(&*x)))}
                                     prop:value={props.signal.value}
                                     style={props.style}
@@ -183,6 +195,6 @@ macro_rules! str_impl {
 
 str_impl!(
     String,
-    Cow<'_, str> { Cow::Owned },
+    Cow<'static, str> { Cow::Owned },
     Oco<'static, str> { Oco::Owned },
 );
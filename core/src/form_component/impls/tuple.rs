@@ -0,0 +1,150 @@
+use super::array::wrap_item;
+use crate::*;
+use ::leptos::prelude::*;
+
+/// Configuration for a tuple of FormFields
+#[derive(Clone, Debug, Default, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+pub struct TupleConfig<Configs: Default> {
+    /// a tuple holding the configuration of each element
+    pub items: Configs,
+    /// labels rendered before each element, in order; elements without a label are rendered without one
+    pub labels: Vec<Oco<'static, str>>,
+    /// class to be placed on container element which wraps both the label and the element
+    #[builder(setter(strip_option))]
+    pub item_container_class: Option<Oco<'static, str>>,
+    /// custom class to be passed into each element's FormField props
+    #[builder(setter(strip_option))]
+    pub item_class: Option<Oco<'static, str>>,
+    /// custom style to be passed into each element's FormField props
+    #[builder(setter(strip_option))]
+    pub item_style: Option<Oco<'static, str>>,
+}
+
+macro_rules! tuple_impl {
+    ($(($($T:ident $El:ident $i:tt),+)),*$(,)?) => { $(
+        impl<$($T: DefaultHtmlElement),+> DefaultHtmlElement for ($($T,)+) {
+            type El = ($($T::El,)+);
+        }
+
+        impl<$($T, $El),+> FormField<($($El,)+)> for ($($T,)+)
+        where
+            $($T: FormField<$El>),+
+        {
+            type Config = TupleConfig<($(<$T as FormField<$El>>::Config,)+)>;
+            type Signal = ($(<$T as FormField<$El>>::Signal,)+);
+
+            fn default_signal(config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                match initial {
+                    Some(initial) => ($($T::default_signal(&config.items.$i, Some(initial.$i)),)+),
+                    None => ($($T::default_signal(&config.items.$i, None),)+),
+                }
+            }
+            fn is_default_value(signal: &Self::Signal) -> bool {
+                $($T::is_default_value(&signal.$i))&&+
+            }
+            fn into_signal(self, config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                match initial {
+                    Some(initial) => ($(self.$i.into_signal(&config.items.$i, Some(initial.$i)),)+),
+                    None => ($(self.$i.into_signal(&config.items.$i, None),)+),
+                }
+            }
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError> {
+                let mut errors = FormErrors::new();
+                let items = ($(
+                    errors.record($T::try_from_signal(signal.$i, &config.items.$i).map_err(|err| err.at_index($i))),
+                )+);
+                errors.into_result()?;
+                Ok(($(items.$i.expect("every element was parsed successfully"),)+))
+            }
+            fn recurse(signal: &Self::Signal) {
+                $($T::recurse(&signal.$i);)+
+            }
+            fn reset_initial_value(signal: &Self::Signal) {
+                $($T::reset_initial_value(&signal.$i);)+
+            }
+            fn has_changed(signal: &Self::Signal) -> bool {
+                $($T::has_changed(&signal.$i))||+
+            }
+            fn validate(signal: &Self::Signal, config: &Self::Config) -> Result<(), FormError> {
+                let mut errors = FormErrors::new();
+                $(errors.record($T::validate(&signal.$i, &config.items.$i).map_err(|err| err.at_index($i)));)+
+                errors.into_result()
+            }
+            fn set_error_at(signal: &Self::Signal, path: &[PathSegment], error: FormError) -> Result<(), FormError> {
+                match path.split_first() {
+                    $(Some((PathSegment::Index($i), path)) => $T::set_error_at(&signal.$i, path, error),)+
+                    _ => Err(error),
+                }
+            }
+            fn collect_errors(signal: &Self::Signal, errors: &mut FormErrors) {
+                $(errors.extend($T::errors(&signal.$i).into_iter().map(|err| err.at_index($i)));)+
+            }
+            fn is_pending(signal: &Self::Signal) -> bool {
+                $($T::is_pending(&signal.$i))||+
+            }
+            fn is_touched(signal: &Self::Signal) -> bool {
+                $($T::is_touched(&signal.$i))||+
+            }
+            fn touch(signal: &Self::Signal) {
+                $($T::touch(&signal.$i);)+
+            }
+            fn redact(&mut self) {
+                $($T::redact(&mut self.$i);)+
+            }
+        }
+
+        impl<$($T, $El),+> FormComponent<($($El,)+)> for ($($T,)+)
+        where
+            $($T: FormComponent<$El>),+
+        {
+            fn render(props: RenderProps<Self::Signal, Self::Config>) -> impl IntoView {
+                let TupleConfig {
+                    items,
+                    labels,
+                    item_container_class,
+                    item_class,
+                    item_style,
+                } = props.config;
+
+                view! {
+                    <div id={props.id} class={props.class} style={props.style}>
+                        $({
+                            let id: Oco<'static, str> = Oco::Owned($i.to_string());
+                            let item_props = RenderProps::builder()
+                                .id(id.clone())
+                                .name(crate::format_form_name(props.name.as_ref(), $i.to_string()))
+                                .class(item_class.clone())
+                                .style(item_style.clone())
+                                .field_changed_class(props.field_changed_class.clone())
+                                .on_change(props.on_change.clone())
+                                .validate_on(props.validate_on)
+                                .signal(props.signal.$i)
+                                .config(items.$i)
+                                .build();
+                            wrap_item(
+                                item_container_class.clone(),
+                                None,
+                                None,
+                                labels.get($i).map(|label| label.to_string()),
+                                id,
+                                <$T as FormComponent<$El>>::render(item_props),
+                            )
+                        })+
+                    </div>
+                }
+            }
+        }
+    )* };
+}
+
+tuple_impl!(
+    (T0 El0 0),
+    (T0 El0 0, T1 El1 1),
+    (T0 El0 0, T1 El1 1, T2 El2 2),
+    (T0 El0 0, T1 El1 1, T2 El2 2, T3 El3 3),
+    (T0 El0 0, T1 El1 1, T2 El2 2, T3 El3 3, T4 El4 4),
+    (T0 El0 0, T1 El1 1, T2 El2 2, T3 El3 3, T4 El4 4, T5 El5 5),
+    (T0 El0 0, T1 El1 1, T2 El2 2, T3 El3 3, T4 El4 4, T5 El5 5, T6 El6 6),
+    (T0 El0 0, T1 El1 1, T2 El2 2, T3 El3 3, T4 El4 4, T5 El5 5, T6 El6 6, T7 El7 7),
+);
@@ -4,6 +4,8 @@ pub use impls::*;
 
 use crate::*;
 use ::leptos::prelude::*;
+use ::std::marker::PhantomData;
+use ::std::sync::Arc;
 
 pub use form_field_component::FormField;
 
@@ -39,13 +41,70 @@ pub trait FormField<El>: Sized {
     fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, FormError>;
     fn recurse(signal: &Self::Signal);
     fn reset_initial_value(signal: &Self::Signal);
-    fn validate(_: Self::Signal) -> Result<(), FormError> {
+    /// Whether the current value differs from the initial value, subscribing to the underlying signals.
+    #[allow(unused_variables)]
+    fn has_changed(signal: &Self::Signal) -> bool {
+        false
+    }
+    /// Runs any validation rules attached to this value, writing the results into the errors of the underlying signals.
+    #[allow(unused_variables)]
+    fn validate(signal: &Self::Signal, config: &Self::Config) -> Result<(), FormError> {
         Ok(())
     }
     #[allow(unused_variables)]
     fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
         f(None)
     }
+    /// Replaces the error displayed for this value, a no-op for types which do not hold an error.
+    #[allow(unused_variables)]
+    fn set_error(signal: &Self::Signal, error: Option<FormError>) {}
+    /// Whether an async validation of this value is in flight, subscribing to the underlying signals.
+    #[allow(unused_variables)]
+    fn is_pending(signal: &Self::Signal) -> bool {
+        false
+    }
+    /// Marks an async validation of this value as in flight, a no-op for types which do not track it.
+    #[allow(unused_variables)]
+    fn set_pending(signal: &Self::Signal, pending: bool) {}
+    /// Whether the user has interacted with this value or any value nested in it, subscribing to the underlying signals.
+    #[allow(unused_variables)]
+    fn is_touched(signal: &Self::Signal) -> bool {
+        false
+    }
+    /// Marks this value and every value nested in it as touched, e.g. upon submission.
+    #[allow(unused_variables)]
+    fn touch(signal: &Self::Signal) {}
+    /// Clears any secret held by this value or any value nested in it, e.g. before it is written to a draft cache.
+    fn redact(&mut self) {}
+    /// Displays `error` on the value at `path` relative to this value, handing the error back if there is no such value.
+    fn set_error_at(signal: &Self::Signal, path: &[PathSegment], error: FormError) -> Result<(), FormError> {
+        match path.is_empty() {
+            true => {
+                Self::set_error(signal, Some(error));
+                Ok(())
+            }
+            false => Err(error),
+        }
+    }
+    /// Pushes the error displayed for this value and the errors of every value nested in it onto `errors`,
+    /// with paths relative to this value, subscribing to the underlying signals.
+    fn collect_errors(signal: &Self::Signal, errors: &mut FormErrors) {
+        Self::with_error(signal, |error| {
+            if let Some(error) = error {
+                errors.push(error.clone());
+            }
+        })
+    }
+    /// Every error displayed for this value or any value nested in it, see [`FormField::collect_errors`].
+    fn errors(signal: &Self::Signal) -> FormErrors {
+        let mut errors = FormErrors::new();
+        Self::collect_errors(signal, &mut errors);
+        errors
+    }
+    /// Whether an error is displayed for this value or any value nested in it, subscribing to the underlying signals.
+    fn has_errors(signal: &Self::Signal) -> bool {
+        !Self::errors(signal).is_empty()
+    }
 }
 
 /// Rendering behavior for a particular data type given the html it is rendered in.
@@ -76,10 +135,130 @@ pub struct RenderProps<T: 'static, Config = ()> {
     pub field_changed_class: Option<Oco<'static, str>>,
     #[builder(default)]
     pub is_optional: bool,
+    /// Called by rendered inputs after their value has been parsed, see [`RenderProps::validate_on`].
+    #[builder(default)]
+    pub on_change: Option<FieldCallback>,
+    /// When rendered inputs parse their value and call [`RenderProps::on_change`].
+    #[builder(default)]
+    pub validate_on: ValidateOn,
+    /// Native html attributes set on rendered inputs.
+    #[builder(default)]
+    pub input_attrs: InputAttrs,
     pub signal: T,
     pub config: Config,
 }
 
+/// A shareable callback passed down to rendered fields through [`RenderProps`].
+#[derive(Clone, Derivative)]
+#[derivative(Debug)]
+pub struct FieldCallback(#[derivative(Debug = "ignore")] Arc<dyn Fn() + Send + Sync>);
+
+impl FieldCallback {
+    pub fn new(f: impl Fn() + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    pub fn call(&self) {
+        (self.0)()
+    }
+}
+
+/// Native html constraint and hint attributes set on the inputs of leaf fields, derived from the
+/// `validate`, `autocomplete`, `inputmode` and `step` field attributes of Form.
+///
+/// Text and numeric inputs are also marked as `required` when their field isn't an `Option`, see
+/// [`RenderProps::is_required`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputAttrs {
+    pub required: bool,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub pattern: Option<Oco<'static, str>>,
+    pub step: Option<Oco<'static, str>>,
+    pub input_mode: Option<Oco<'static, str>>,
+    pub autocomplete: Option<Oco<'static, str>>,
+}
+
+/// When a field is validated, see the `validate_on` attribute of Form.
+///
+/// Regardless of the mode, every field is validated upon submission and a field displaying an error
+/// is validated again on every input so that the error goes away as soon as it is fixed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ValidateOn {
+    /// On every input, once the field has been touched.
+    Input,
+    /// When the input commits a change.
+    #[default]
+    Change,
+    /// When the input loses focus.
+    Blur,
+    /// Only upon submission.
+    Submit,
+}
+
+/// Event handling shared by the inputs of leaf fields: tracks whether the field was touched and,
+/// depending on [`RenderProps::validate_on`], parses its value and calls [`RenderProps::on_change`].
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Copy(bound = ""))]
+pub struct FieldEvents<T: FormField<El>, El> {
+    signal: StoredValue<T::Signal>,
+    config: StoredValue<T::Config>,
+    on_change: StoredValue<Option<FieldCallback>>,
+    is_optional: bool,
+    validate_on: ValidateOn,
+    ty: PhantomData<fn() -> El>,
+}
+
+impl<T: FormField<El>, El> FieldEvents<T, El>
+where
+    T::Signal: Send + Sync,
+{
+    pub fn new(props: &RenderProps<T::Signal, T::Config>) -> Self {
+        Self {
+            signal: StoredValue::new(props.signal.clone()),
+            config: StoredValue::new(props.config.clone()),
+            on_change: StoredValue::new(props.on_change.clone()),
+            is_optional: props.is_optional,
+            validate_on: props.validate_on,
+            ty: PhantomData,
+        }
+    }
+
+    /// Handles `event` emitted by the field's input, `Submit` is never emitted by inputs.
+    pub fn handle(&self, event: ValidateOn) {
+        let signal = self.signal.get_value();
+        if event != ValidateOn::Input {
+            T::touch(&signal);
+        }
+        let is_invalid = T::with_error(&signal, |error| error.is_some());
+        let validate = match (event, self.validate_on) {
+            (ValidateOn::Input, ValidateOn::Input) => is_invalid || T::is_touched(&signal),
+            (ValidateOn::Input, _) => is_invalid,
+            (ValidateOn::Change, ValidateOn::Input | ValidateOn::Change) => true,
+            (ValidateOn::Blur, ValidateOn::Blur) => true,
+            _ => false,
+        };
+        if !validate {
+            return;
+        }
+
+        let error = match self.is_optional && T::is_default_value(&signal) {
+            true => None,
+            false => self
+                .config
+                .with_value(|config| T::try_from_signal(signal.clone(), config).err()),
+        };
+        if error.is_some() || is_invalid {
+            T::set_error(&signal, error);
+        }
+        self.on_change.with_value(|on_change| {
+            if let Some(on_change) = on_change.as_ref() {
+                on_change.call();
+            }
+        });
+    }
+}
+
 /// A wrapper holding a signal for a current state, an initial state, and possibly an error.
 #[derive(Debug, Deref, DerefMut, Derivative, TypedBuilder)]
 #[derivative(Copy(bound = ""), Clone(bound = ""))]
@@ -89,6 +268,12 @@ pub struct FormFieldSignal<T: Send + Sync + Clone + 'static> {
     pub value: RwSignal<T>,
     pub initial: RwSignal<Option<T>>,
     pub error: RwSignal<Option<FormError>>,
+    /// Whether an async validation of this field is in flight (including its debounce period).
+    #[builder(default = RwSignal::new(false))]
+    pub pending: RwSignal<bool>,
+    /// Whether the user has interacted with this field, i.e. committed a change or left its input.
+    #[builder(default = RwSignal::new(false))]
+    pub touched: RwSignal<bool>,
 }
 
 impl<T: Default + PartialEq + Clone + Send + Sync + 'static, Config> RenderProps<FormFieldSignal<T>, Config> {
@@ -129,6 +314,13 @@ impl<T: Default + PartialEq + Clone + Send + Sync + 'static, Config> RenderProps
     }
 }
 
+impl<T, Config> RenderProps<T, Config> {
+    /// Whether the input is marked as `required`: by the explicit `required` rule or unless the field is an `Option`.
+    pub fn is_required(&self) -> bool {
+        self.input_attrs.required || !self.is_optional
+    }
+}
+
 impl<T: Default + PartialEq + Clone + Send + Sync + 'static> FormFieldSignal<T> {
     pub fn has_changed(&self) -> bool {
         self.value.with_untracked(|value| {
@@ -138,6 +330,13 @@ impl<T: Default + PartialEq + Clone + Send + Sync + 'static> FormFieldSignal<T>
             })
         })
     }
+
+    /// Same as [`FormFieldSignal::has_changed`] but tracks both the value and initial signals.
+    pub fn has_changed_tracked(&self) -> bool {
+        self.value.track();
+        self.initial.track();
+        self.has_changed()
+    }
 }
 
 impl<T: DefaultHtmlElement> DefaultHtmlElement for Option<T> {
@@ -175,12 +374,44 @@ where
     fn reset_initial_value(signal: &Self::Signal) {
         T::reset_initial_value(signal);
     }
-    fn validate(signal: Self::Signal) -> Result<(), FormError> {
-        T::validate(signal)
+    fn has_changed(signal: &Self::Signal) -> bool {
+        T::has_changed(signal)
+    }
+    fn validate(signal: &Self::Signal, config: &Self::Config) -> Result<(), FormError> {
+        match Self::is_default_value(signal) {
+            true => Ok(()),
+            false => T::validate(signal, config),
+        }
     }
     fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&FormError>) -> O) -> O {
         T::with_error(signal, f)
     }
+    fn set_error(signal: &Self::Signal, error: Option<FormError>) {
+        T::set_error(signal, error)
+    }
+    fn is_pending(signal: &Self::Signal) -> bool {
+        T::is_pending(signal)
+    }
+    fn set_pending(signal: &Self::Signal, pending: bool) {
+        T::set_pending(signal, pending)
+    }
+    fn is_touched(signal: &Self::Signal) -> bool {
+        T::is_touched(signal)
+    }
+    fn touch(signal: &Self::Signal) {
+        T::touch(signal)
+    }
+    fn redact(&mut self) {
+        if let Some(value) = self {
+            value.redact()
+        }
+    }
+    fn set_error_at(signal: &Self::Signal, path: &[PathSegment], error: FormError) -> Result<(), FormError> {
+        T::set_error_at(signal, path, error)
+    }
+    fn collect_errors(signal: &Self::Signal, errors: &mut FormErrors) {
+        T::collect_errors(signal, errors)
+    }
 }
 
 impl<El, T> FormComponent<El> for Option<T>
@@ -200,6 +431,8 @@ impl<T: Clone + Default + Send + Sync + 'static> Default for FormFieldSignal<T>
             value: RwSignal::new(default.clone()),
             initial: RwSignal::new(Some(default)),
             error: RwSignal::new(None),
+            pending: RwSignal::new(false),
+            touched: RwSignal::new(false),
         }
     }
 }
@@ -210,6 +443,8 @@ impl<T: Clone + Send + Sync + 'static> FormFieldSignal<T> {
             value: RwSignal::new(value),
             error: RwSignal::new(Default::default()),
             initial: RwSignal::new(initial),
+            pending: RwSignal::new(false),
+            touched: RwSignal::new(false),
         }
     }
 }
@@ -220,6 +455,8 @@ impl<T: Clone + Default + Send + Sync + 'static> FormFieldSignal<T> {
             value: RwSignal::new(Default::default()),
             error: RwSignal::new(Default::default()),
             initial: RwSignal::new(initial),
+            pending: RwSignal::new(false),
+            touched: RwSignal::new(false),
         }
     }
 }
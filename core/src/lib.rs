@@ -15,18 +15,15 @@ extern crate typed_builder;
 
 pub mod cache;
 pub mod components;
+mod error;
 mod form_component;
+pub mod validation;
 
+pub use error::*;
 pub use form_component::*;
 
 use ::leptos::prelude::*;
-
-/// Error returned while rendering or parsing html form.
-#[derive(Clone, Debug, Error)]
-pub enum FormError {
-    #[error("{0}")]
-    Parse(String),
-}
+use ::send_wrapper::SendWrapper;
 
 /// Wrapper type used for providing the initial and current value of the form's main type.
 #[derive(Clone, Copy, Debug)]
@@ -38,12 +35,6 @@ pub struct FormDiff<T> {
 pub trait MapSubmit<T, U>: Fn(FormDiff<T>) -> U {}
 impl<T, U, F> MapSubmit<T, U> for F where F: Fn(FormDiff<T>) -> U {}
 
-impl FormError {
-    pub fn parse(err: impl ::std::fmt::Display) -> Self {
-        Self::Parse(format!("{err}"))
-    }
-}
-
 #[doc(hidden)]
 pub fn format_form_id(id_prefix: Option<&Oco<'_, str>>, id: impl Into<Oco<'static, str>>) -> Oco<'static, str> {
     let id = id.into();
@@ -67,6 +58,23 @@ pub fn format_form_name(
     }
 }
 
+/// Adapts a server function taking `multipart/form-data` (e.g. `server_fn::codec::MultipartData`)
+/// to an action dispatched with the entries of a submitted form, see [`multipart_form_data`].
+#[doc(hidden)]
+pub fn multipart_server_fn<T: From<web_sys::FormData>, U>(
+    f: impl Fn(T) -> U,
+) -> impl Fn(&SendWrapper<web_sys::FormData>) -> U {
+    move |data: &SendWrapper<web_sys::FormData>| f(T::from(web_sys::FormData::clone(data)))
+}
+
+/// Collects the entries of the submitted form element, including the files selected in its file fields.
+#[doc(hidden)]
+pub fn multipart_form_data(ev: &leptos::ev::SubmitEvent) -> SendWrapper<web_sys::FormData> {
+    use ::wasm_bindgen::{JsCast, UnwrapThrowExt};
+    let form = ev.target().unwrap_throw().unchecked_into::<web_sys::HtmlFormElement>();
+    SendWrapper::new(web_sys::FormData::new_with_form(&form).unwrap_throw())
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum LabelCase {
     /// `"camelCase"`
@@ -0,0 +1,446 @@
+//! Rules backing the `#[form(validate(..))]` field attribute.
+//!
+//! Each rule receives the parsed value of a field and returns a [`FormError::Validation`] when the value
+//! is rejected. Rules other than [`required`] treat an absent value (e.g. `None`) as valid so that
+//! optional fields are only checked once they are filled in.
+
+use crate::*;
+use ::indexmap::{IndexMap, IndexSet};
+use ::leptos::prelude::*;
+use ::leptos::task::spawn_local;
+use ::std::borrow::Cow;
+use ::std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use ::std::fmt::Display;
+use ::std::future::Future;
+use ::std::marker::PhantomData;
+use ::std::num::*;
+use ::std::pin::Pin;
+use ::std::sync::{Arc, Mutex};
+use ::std::time::Duration;
+
+/// Parses the value held in `signal` and runs `rules` against it,
+/// writing the outcome into the field's error.
+pub fn validate_field<T: FormField<El>, El>(
+    signal: &T::Signal,
+    config: &T::Config,
+    rules: impl FnOnce(&T) -> Result<(), FormError>,
+) -> Result<(), FormError> {
+    let result = T::try_from_signal(signal.clone(), config).and_then(|value| rules(&value));
+    T::set_error(signal, result.as_ref().err().and_then(own_error));
+    result
+}
+
+/// Same as [`validate_field`] for a field which also has an async validator:
+/// the field is only valid once no async validation is pending and the latest one passed.
+pub fn validate_async_field<T: FormField<El>, El>(
+    signal: &T::Signal,
+    config: &T::Config,
+    rules: impl FnOnce(&T) -> Result<(), FormError>,
+) -> Result<(), FormError> {
+    if let Err(err) = T::try_from_signal(signal.clone(), config).and_then(|value| rules(&value)) {
+        T::set_error(signal, own_error(&err));
+        return Err(err);
+    }
+    if T::is_pending(signal) {
+        return Err(FormError::validation("validation is still in progress").with_code("pending"));
+    }
+    T::with_error(signal, |error| error.cloned()).map_or(Ok(()), Err)
+}
+
+/// Debounced async validation of a single field, backing the `async_custom` validation rule.
+///
+/// Each call to [`AsyncValidator::schedule`] marks the field as pending and restarts the debounce period,
+/// after which the field's synchronous rules and then the async check are run against its current value.
+/// Outcomes of checks started before the latest call are discarded.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct AsyncValidator<T: FormField<El>, El> {
+    signal: T::Signal,
+    config: T::Config,
+    debounce: Duration,
+    rules: Arc<dyn Fn(&T) -> Result<(), FormError> + Send + Sync>,
+    #[allow(clippy::type_complexity)]
+    check: Arc<dyn Fn(T) -> Pin<Box<dyn Future<Output = Result<(), FormError>>>> + Send + Sync>,
+    state: Arc<Mutex<AsyncValidatorState>>,
+    ty: PhantomData<fn() -> El>,
+}
+
+#[derive(Default)]
+struct AsyncValidatorState {
+    generation: u64,
+    timeout: Option<TimeoutHandle>,
+    /// Outcome of the latest check, once resolved.
+    verdict: Option<Option<FormError>>,
+}
+
+impl<T: FormField<El> + 'static, El: 'static> AsyncValidator<T, El>
+where
+    T::Signal: Send + Sync,
+{
+    pub fn new<Fut: Future<Output = Result<(), FormError>> + 'static>(
+        signal: T::Signal,
+        config: T::Config,
+        debounce_ms: u64,
+        rules: impl Fn(&T) -> Result<(), FormError> + Send + Sync + 'static,
+        check: impl Fn(T) -> Fut + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            signal,
+            config,
+            debounce: Duration::from_millis(debounce_ms),
+            rules: Arc::new(rules),
+            check: Arc::new(move |value| Box::pin(check(value))),
+            state: Default::default(),
+            ty: PhantomData,
+        }
+    }
+
+    /// Cancels any in-flight check and starts a new debounce period.
+    pub fn schedule(&self) {
+        let generation = {
+            let mut state = self.state.lock().unwrap();
+            if let Some(timeout) = state.timeout.take() {
+                timeout.clear();
+            }
+            state.generation += 1;
+            state.verdict = None;
+            state.generation
+        };
+        T::set_pending(&self.signal, true);
+
+        let validator = self.clone();
+        let timeout = set_timeout_with_handle(move || validator.run(generation), self.debounce).ok();
+        // no check runs when the timeout could not be set, e.g. during server side rendering
+        if timeout.is_none() {
+            T::set_pending(&self.signal, false);
+        }
+        self.state.lock().unwrap().timeout = timeout;
+    }
+
+    /// Re-applies the outcome of the latest resolved check, e.g. after an input cleared the field's error.
+    pub fn restore(&self) {
+        let verdict = self.state.lock().unwrap().verdict.clone();
+        if let Some(error) = verdict {
+            T::set_error(&self.signal, error);
+        }
+    }
+
+    fn run(self, generation: u64) {
+        let value =
+            T::try_from_signal(self.signal.clone(), &self.config).and_then(|value| (self.rules)(&value).map(|_| value));
+        match value {
+            Ok(value) => {
+                let check = (self.check)(value);
+                spawn_local(async move {
+                    let result = check.await;
+                    self.resolve(generation, result.err());
+                });
+            }
+            Err(err) => self.resolve(generation, Some(err)),
+        }
+    }
+
+    fn resolve(&self, generation: u64, error: Option<FormError>) {
+        {
+            let mut state = self.state.lock().unwrap();
+            // a newer check was scheduled in the meantime
+            if state.generation != generation {
+                return;
+            }
+            state.timeout = None;
+            state.verdict = Some(error.as_ref().and_then(own_error));
+        }
+        T::set_error(&self.signal, error.as_ref().and_then(own_error));
+        T::set_pending(&self.signal, false);
+    }
+}
+
+/// The errors belonging to the validated value itself, errors of values nested in it are displayed by those.
+fn own_error(error: &FormError) -> Option<FormError> {
+    error
+        .iter()
+        .filter(|error| error.path().is_some_and(FieldPath::is_root))
+        .cloned()
+        .collect::<FormErrors>()
+        .into_result()
+        .err()
+}
+
+/// Errors returned by a struct-level validator, see the `validate_with` struct attribute.
+#[derive(Clone, Debug, Default)]
+pub struct ValidationErrors {
+    /// Errors displayed on the field with the given name, i.e. its identifier (or index for tuple structs).
+    pub fields: IndexMap<Cow<'static, str>, FormError>,
+    /// Error displayed in the form-level error slot.
+    pub form: Option<FormError>,
+}
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_field(mut self, name: impl Into<Cow<'static, str>>, error: FormError) -> Self {
+        self.fields.insert(name.into(), error);
+        self
+    }
+
+    pub fn with_form(mut self, error: FormError) -> Self {
+        self.form = Some(error);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.form.is_none() && self.fields.is_empty()
+    }
+
+    /// All errors as a single [`FormError`], field errors have their field name prepended to their path.
+    pub fn to_result(&self) -> Result<(), FormError> {
+        let mut errors = FormErrors::new();
+        if let Some(error) = self.form.clone() {
+            errors.push(error);
+        }
+        for (name, error) in &self.fields {
+            errors.push(error.clone().in_field(name.clone()));
+        }
+        errors.into_result()
+    }
+}
+
+/// Values which can be checked by the `required` rule.
+pub trait Required {
+    fn is_present(&self) -> bool;
+}
+
+/// Values whose length can be checked by the `min_len` and `max_len` rules.
+pub trait Length {
+    /// What the length is counted in, used in error messages.
+    const UNIT: &'static str;
+    /// The length of the value, `None` skips the rule.
+    fn length(&self) -> Option<usize>;
+}
+
+/// Values which can be checked by the `range` rule.
+pub trait Bounded {
+    type Value: PartialOrd + Display;
+    /// The value compared against the bounds, `None` skips the rule.
+    fn bounded(&self) -> Option<&Self::Value>;
+}
+
+/// Values which can be checked by the `pattern` and `email` rules.
+pub trait Text {
+    /// The text of the value, `None` skips the rule.
+    fn text(&self) -> Option<&str>;
+}
+
+pub fn required<T: Required>(value: &T) -> Result<(), FormError> {
+    match value.is_present() {
+        true => Ok(()),
+        false => Err(FormError::validation("this field is required").with_code("required")),
+    }
+}
+
+pub fn min_len<T: Length>(value: &T, min: usize) -> Result<(), FormError> {
+    match value.length() {
+        Some(len) if len < min => Err(FormError::validation(format!("must be at least {min} {}", T::UNIT))
+            .with_code("min_len")
+            .with_param("min", min)),
+        _ => Ok(()),
+    }
+}
+
+pub fn max_len<T: Length>(value: &T, max: usize) -> Result<(), FormError> {
+    match value.length() {
+        Some(len) if len > max => Err(FormError::validation(format!("must be at most {max} {}", T::UNIT))
+            .with_code("max_len")
+            .with_param("max", max)),
+        _ => Ok(()),
+    }
+}
+
+pub fn range<T: Bounded>(value: &T, min: Option<T::Value>, max: Option<T::Value>) -> Result<(), FormError> {
+    let Some(value) = value.bounded() else { return Ok(()) };
+    if let Some(min) = min {
+        if *value < min {
+            return Err(FormError::validation(format!("must be at least {min}"))
+                .with_code("range")
+                .with_param("min", min));
+        }
+    }
+    if let Some(max) = max {
+        if *value > max {
+            return Err(FormError::validation(format!("must be at most {max}"))
+                .with_code("range")
+                .with_param("max", max));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the whole value matches `pattern`, mirroring the html `pattern` attribute.
+/// Compiled patterns are cached per thread.
+///
+/// Patterns given to the `Form` derive are checked when it is expanded; an invalid pattern passed here directly
+/// fails validation rather than panicking.
+#[cfg(feature = "regex")]
+pub fn pattern<T: Text>(value: &T, pattern: &'static str) -> Result<(), FormError> {
+    use ::std::{cell::RefCell, collections::HashMap};
+
+    thread_local! {
+        static PATTERNS: RefCell<HashMap<&'static str, Option<::regex::Regex>>> = Default::default();
+    }
+
+    let Some(text) = value.text() else { return Ok(()) };
+    let is_match = PATTERNS.with_borrow_mut(|patterns| {
+        patterns
+            .entry(pattern)
+            .or_insert_with(|| ::regex::Regex::new(&format!("^(?:{pattern})$")).ok())
+            .as_ref()
+            .map(|regex| regex.is_match(text))
+    });
+    match is_match {
+        Some(true) => Ok(()),
+        None => Err(FormError::validation("has an invalid pattern")
+            .with_code("pattern")
+            .with_param("pattern", pattern)),
+        Some(false) => Err(FormError::validation("does not match the required format")
+            .with_code("pattern")
+            .with_param("pattern", pattern)),
+    }
+}
+
+pub fn email<T: Text>(value: &T) -> Result<(), FormError> {
+    let Some(text) = value.text() else { return Ok(()) };
+    let is_email = match text.rsplit_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !text.chars().any(char::is_whitespace)
+                && domain.contains('.')
+                && domain.split('.').all(|part| !part.is_empty())
+        }
+        None => false,
+    };
+    match is_email {
+        true => Ok(()),
+        false => Err(FormError::validation("must be a valid email address").with_code("email")),
+    }
+}
+
+macro_rules! str_rules {
+    ($($ty:ty),*$(,)?) => { $(
+        impl Required for $ty {
+            fn is_present(&self) -> bool {
+                !self.is_empty()
+            }
+        }
+        impl Length for $ty {
+            const UNIT: &'static str = "characters";
+            fn length(&self) -> Option<usize> {
+                Some(self.chars().count())
+            }
+        }
+        impl Text for $ty {
+            fn text(&self) -> Option<&str> {
+                Some(::std::ops::Deref::deref(self))
+            }
+        }
+    )* };
+}
+
+macro_rules! bounded {
+    ($($ty:ty),*$(,)?) => { $(
+        impl Bounded for $ty {
+            type Value = Self;
+            fn bounded(&self) -> Option<&Self::Value> {
+                Some(self)
+            }
+        }
+    )* };
+}
+
+str_rules!(String, Cow<'_, str>, Oco<'_, str>);
+
+bounded!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+bounded!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+);
+#[cfg(feature = "num-bigint")]
+bounded!(num_bigint::BigInt, num_bigint::BigUint);
+#[cfg(feature = "bigdecimal")]
+bounded!(bigdecimal::BigDecimal);
+
+/// A `bool` is present when it is `true`, e.g. for an "accept the terms" checkbox.
+impl Required for bool {
+    fn is_present(&self) -> bool {
+        *self
+    }
+}
+
+impl<T> Required for Option<T> {
+    fn is_present(&self) -> bool {
+        self.is_some()
+    }
+}
+
+impl<T: Length> Length for Option<T> {
+    const UNIT: &'static str = T::UNIT;
+    fn length(&self) -> Option<usize> {
+        self.as_ref().and_then(T::length)
+    }
+}
+
+impl<T: Bounded> Bounded for Option<T> {
+    type Value = T::Value;
+    fn bounded(&self) -> Option<&Self::Value> {
+        self.as_ref().and_then(T::bounded)
+    }
+}
+
+impl<T: Text> Text for Option<T> {
+    fn text(&self) -> Option<&str> {
+        self.as_ref().and_then(T::text)
+    }
+}
+
+macro_rules! collection_rules {
+    ($($ty:ident),*$(,)?) => { $(
+        impl<T> Required for $ty<T> {
+            fn is_present(&self) -> bool {
+                !self.is_empty()
+            }
+        }
+        impl<T> Length for $ty<T> {
+            const UNIT: &'static str = "items";
+            fn length(&self) -> Option<usize> {
+                Some(self.len())
+            }
+        }
+    )* };
+    (@map $($ty:ident),*$(,)?) => { $(
+        impl<K, V> Required for $ty<K, V> {
+            fn is_present(&self) -> bool {
+                !self.is_empty()
+            }
+        }
+        impl<K, V> Length for $ty<K, V> {
+            const UNIT: &'static str = "entries";
+            fn length(&self) -> Option<usize> {
+                Some(self.len())
+            }
+        }
+    )* };
+}
+
+collection_rules!(Vec, HashSet, BTreeSet, IndexSet);
+collection_rules!(@map HashMap, BTreeMap, IndexMap);
@@ -26,6 +26,16 @@ pub mod prelude {
     #[cfg(feature = "chrono")]
     pub use super::config::chrono::*;
     pub use super::config::collections::*;
+    #[cfg(feature = "jiff")]
+    pub use super::config::jiff::*;
+    #[cfg(feature = "time")]
+    pub use super::config::time::*;
+    pub use super::config::{
+        BoolConfig, Choice, ChoiceConfig, ChoiceOptions, Color, ColorConfig, DurationConfig, DurationFormat,
+        DurationUnit, ExcessPrecision, FileConfig, FileUpload, FileUploads, NumConfig, NumLocale, NumPresentation,
+        PasswordStrength, RadioGroup, Secret, SecretConfig, Slider, SliderConfig,
+    };
+    pub use super::validation::ValidationErrors;
     pub use super::*;
 }
 
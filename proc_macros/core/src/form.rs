@@ -2,7 +2,7 @@ use ::convert_case::*;
 use ::darling::{
     ast::{NestedMeta, Style},
     util::SpannedValue,
-    FromDeriveInput, FromField, FromMeta,
+    FromDeriveInput, FromField, FromMeta, FromVariant,
 };
 use ::derive_more::IsVariant;
 use ::itertools::Itertools;
@@ -19,7 +19,7 @@ use ::syn::{parse2, parse_str};
 #[darling(
     attributes(form),
     forward_attrs(allow, doc, cfg),
-    supports(struct_named, struct_tuple),
+    supports(struct_named, struct_tuple, enum_any),
     and_then = "Self::one_component_kind"
 )]
 struct FormOpts {
@@ -32,11 +32,14 @@ struct FormOpts {
     id: Option<StringExpr>,
     island: Option<ComponentConfigSpanned>,
     label: Option<FormLabel>,
+    selector: Option<SpannedValue<VariantSelector>>,
+    validate_on: Option<ValidateOn>,
+    validate_with: Option<SpannedValue<syn::Path>>,
     wrapper: Option<bool>,
     // forwarded fields
     vis: syn::Visibility,
     ident: syn::Ident,
-    data: darling::ast::Data<(), SpannedValue<FormField>>,
+    data: darling::ast::Data<FormVariant, SpannedValue<FormField>>,
 
     #[darling(default)]
     i18n: Option<I18nStructOptions>,
@@ -64,7 +67,9 @@ struct ComponentConfig {
     cache: Option<Cache>,
     class: Option<StringExpr>,
     field_changed_class: Option<StringExpr>,
+    field_errors: Option<bool>,
     map_submit: Option<MapSubmit>,
+    multipart: Option<bool>,
     name: Option<syn::Ident>,
     on_error: Option<syn::Expr>,
     on_loading: Option<syn::Expr>,
@@ -136,14 +141,19 @@ enum Action {
 #[derive(Clone, Debug, FromField)]
 #[darling(attributes(form))]
 struct FormField {
+    autocomplete: Option<StringExpr>,
     class: Option<StringExpr>,
     config: Option<syn::Expr>,
     el: Option<Element>,
     error: Option<SpannedValue<ErrorHandler>>,
     group: Option<SpannedValue<usize>>,
     id: Option<StringExpr>,
+    inputmode: Option<StringExpr>,
     label: Option<FieldLabel>,
+    step: Option<StringExpr>,
     style: Option<StringExpr>,
+    validate: Option<Validate>,
+    validate_on: Option<ValidateOn>,
     // forwarded fields
     ident: Option<syn::Ident>,
     ty: syn::Type,
@@ -152,6 +162,59 @@ struct FormField {
 #[derive(Clone, Debug)]
 struct Element(syn::Type);
 
+/// Rules checked against the parsed value of a field, backed by `leptos_form::validation`.
+#[derive(Clone, Debug, Default, FromMeta)]
+struct Validate {
+    async_custom: Option<syn::Path>,
+    custom: Option<syn::Path>,
+    debounce_ms: Option<syn::LitInt>,
+    email: Option<bool>,
+    max_len: Option<syn::Expr>,
+    min_len: Option<syn::Expr>,
+    pattern: Option<syn::LitStr>,
+    range: Option<SpannedValue<ValidateRange>>,
+    required: Option<bool>,
+}
+
+#[derive(Clone, Debug, FromMeta)]
+struct ValidateRange {
+    min: Option<syn::Expr>,
+    max: Option<syn::Expr>,
+}
+
+#[derive(Clone, Debug, FromVariant)]
+#[darling(attributes(form), forward_attrs(default))]
+struct FormVariant {
+    label: Option<syn::LitStr>,
+    // forwarded fields
+    ident: syn::Ident,
+    fields: darling::ast::Fields<syn::Field>,
+    attrs: Vec<syn::Attribute>,
+}
+
+/// How the active variant of an enum is chosen.
+#[derive(Clone, Copy, Debug, Default, FromMeta)]
+enum VariantSelector {
+    #[darling(rename = "radio")]
+    Radio,
+    #[darling(rename = "select")]
+    #[default]
+    Select,
+}
+
+/// When fields are validated, mirrors `leptos_form::ValidateOn`.
+#[derive(Clone, Copy, Debug, FromMeta)]
+enum ValidateOn {
+    #[darling(rename = "input")]
+    Input,
+    #[darling(rename = "change")]
+    Change,
+    #[darling(rename = "blur")]
+    Blur,
+    #[darling(rename = "submit")]
+    Submit,
+}
+
 #[derive(Clone, Debug, Default, FromMeta, IsVariant)]
 enum FieldLabel {
     #[darling(rename = "adjacent")]
@@ -285,6 +348,9 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
         internal,
         island,
         label: form_label,
+        selector,
+        validate_on: form_validate_on,
+        validate_with,
         vis,
         wrapper,
         i18n,
@@ -340,14 +406,52 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
     let wasm_bindgen_krate: syn::Path = parse2(quote!(#leptos_form_krate::internal::wasm_bindgen))?;
     let web_sys_krate: syn::Path = parse2(quote!(#leptos_form_krate::internal::web_sys))?;
 
-    let fields = data.take_struct().unwrap();
+    let fields = match data {
+        darling::ast::Data::Struct(fields) => fields,
+        darling::ast::Data::Enum(variants) => {
+            if let Some(component_config) = component.as_ref().or(island.as_ref()) {
+                return Err(Error::new(
+                    component_config.span(),
+                    format!(
+                        "`{}` cannot be derived on an enum, consider wrapping the enum in a struct which derives Form",
+                        component_config.field,
+                    ),
+                ));
+            }
+            if wrapper.is_some() {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "`wrapper` can only be used on newtype structs",
+                ));
+            }
+            if let Some(validate_with) = validate_with.as_ref() {
+                return Err(Error::new(
+                    validate_with.span(),
+                    "`validate_with` can only be used on structs",
+                ));
+            }
+            return derive_enum_form(
+                &ast,
+                &vis,
+                &ident,
+                variants,
+                selector.map(|x| *x).unwrap_or_default(),
+                &leptos_form_krate,
+                &leptos_krate,
+            );
+        }
+    };
+
+    if let Some(selector) = selector.as_ref() {
+        return Err(Error::new(selector.span(), "`selector` can only be used on enums"));
+    }
 
     let component_ty = ident.clone();
     let signal_ty = signal_ident.clone();
     let config_ty = config_ident.clone();
 
     #[allow(clippy::type_complexity)]
-    let (field_groups, field_axs, field_tys, field_el_tys, configs, signal_fields, config_fields): (
+    let (field_groups, field_axs, field_tys, field_el_tys, configs, mut signal_fields, config_fields): (
         Vec<_>,
         Vec<_>,
         Vec<_>,
@@ -406,6 +510,21 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
         })
         .multiunzip();
 
+    // struct-level errors (see `validate_with`) are held in an additional signal field
+    let errors_ax = match fields.style {
+        Style::Struct => quote!(__errors),
+        _ => parse_str(&fields.len().to_string())?,
+    };
+    signal_fields.push(syn::Field {
+        attrs: Default::default(),
+        vis: syn::Visibility::Public(Default::default()),
+        mutability: syn::FieldMutability::None,
+        ident: (fields.style == Style::Struct).then(|| format_ident!("__errors")),
+        colon_token: Some(Default::default()),
+        ty: parse2(quote!(#leptos_krate::prelude::RwSignal<#leptos_form_krate::validation::ValidationErrors>))?,
+    });
+    let new_errors_signal = quote!(#leptos_krate::prelude::RwSignal::new(Default::default()));
+
     let (build_props, field_id_idents, field_view_idents, error_view_idents): (Vec<_>, Vec<_>, Vec<_>, Vec<_>) = fields
         .iter()
         .enumerate()
@@ -439,6 +558,72 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
 
             let rendered_error = render_error(&leptos_krate, form_error_handler.as_ref(), field.error.as_ref(), &error_ident)?;
 
+            // fields with validation rules re-validate whenever one of their inputs commits a change,
+            // fields with an async validator are instead re-validated (debounced) whenever their value changes
+            let validate_on_change = field.validate.as_ref().map(|validate| {
+                let rules = validate.rules(&leptos_form_krate, field_ty)?;
+                if let Some(async_custom) = validate.async_custom.as_ref() {
+                    let debounce_ms = validate.debounce_ms()?;
+                    let async_validator_ident = format_ident!("_{field_ax}_async_validator");
+                    return Ok(quote!(
+                        let #async_validator_ident = #leptos_form_krate::validation::AsyncValidator::<#field_ty, #field_el_ty>::new(
+                            #build_props_ident.signal.clone(),
+                            #build_props_ident.config.clone(),
+                            #debounce_ms,
+                            #rules,
+                            #async_custom,
+                        );
+                        #leptos_krate::Effect::new({
+                            let signal = #build_props_ident.signal.clone();
+                            let async_validator = #async_validator_ident.clone();
+                            move |prev: Option<()>| {
+                                <#field_ty as #leptos_form_krate::FormField<#field_el_ty>>::has_changed(&signal);
+                                if prev.is_some() {
+                                    async_validator.schedule();
+                                }
+                            }
+                        });
+                        let #build_props_ident = #leptos_form_krate::RenderProps {
+                            on_change: Some(#leptos_form_krate::FieldCallback::new({
+                                let async_validator = #async_validator_ident.clone();
+                                let on_change = #props_ident.on_change.clone();
+                                move || {
+                                    async_validator.restore();
+                                    if let Some(on_change) = on_change.as_ref() {
+                                        on_change.call();
+                                    }
+                                }
+                            })),
+                            ..#build_props_ident
+                        };
+                    ));
+                }
+                Ok::<_, Error>(quote!(
+                    let #build_props_ident = #leptos_form_krate::RenderProps {
+                        on_change: Some(#leptos_form_krate::FieldCallback::new({
+                            let signal = #build_props_ident.signal.clone();
+                            let config = #build_props_ident.config.clone();
+                            let on_change = #props_ident.on_change.clone();
+                            move || {
+                                let _ = #leptos_form_krate::validation::validate_field::<#field_ty, #field_el_ty>(&signal, &config, #rules);
+                                if let Some(on_change) = on_change.as_ref() {
+                                    on_change.call();
+                                }
+                            }
+                        })),
+                        ..#build_props_ident
+                    };
+                ))
+            }).transpose()?;
+
+            let input_attrs = input_attrs(&leptos_form_krate, &leptos_krate, field);
+
+            // fields inherit the validation mode of their parent unless it is set on the field or the struct
+            let validate_on = match field.validate_on.or(form_validate_on) {
+                Some(validate_on) => validate_on.tokens(&leptos_form_krate),
+                None => quote!(#props_ident.validate_on),
+            };
+
             let field_changed_class = component
                 .as_ref()
                 .or(island.as_ref())
@@ -465,9 +650,13 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
                         #(.class(#class))*
                         #(.style(#style))*
                         .field_changed_class(#field_changed_class)
+                        .on_change(#props_ident.on_change.clone())
+                        .validate_on(#validate_on)
+                        #input_attrs
                         .signal(#props_ident.signal.#field_ax.clone())
                         .config(#config)
                         .build();
+                    #validate_on_change
 
                     let #error_view_ident = move || <#field_ty as #leptos_form_krate::FormField<#field_el_ty>>::with_error(&#build_props_ident.signal, |error| match error {
                         Some(form_error) => {
@@ -489,6 +678,75 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
         .into_iter()
         .multiunzip();
 
+    // errors of each field are collected with the field prepended to their path,
+    // wrapper structs are transparent so their single field is not part of the path
+    let field_value_idents = field_axs
+        .iter()
+        .map(|field_ax| format_ident!("_{field_ax}_value"))
+        .collect::<Vec<_>>();
+    let (field_path_segments, field_error_paths): (Vec<_>, Vec<_>) = field_axs
+        .iter()
+        .map(|field_ax| {
+            let field_name = field_ax.to_string();
+            match is_wrapper {
+                true => (quote!(), quote!()),
+                false => (
+                    quote!(.map_err(|err| err.in_field(#field_name))),
+                    quote!(.map(|err| err.in_field(#field_name))),
+                ),
+            }
+        })
+        .unzip();
+
+    let set_error_at_body = match is_wrapper {
+        true => quote!(
+            #(<#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::set_error_at(&signal.#field_axs, path, error))*
+        ),
+        false => {
+            let field_names = field_axs.iter().map(|field_ax| field_ax.to_string());
+            quote!(
+                match path.split_first() {
+                    None => {
+                        <Self as #leptos_form_krate::FormField<#leptos_krate::prelude::AnyView>>::set_error(signal, Some(error));
+                        Ok(())
+                    }
+                    #(Some((#leptos_form_krate::PathSegment::Field(name), path)) if name == #field_names => {
+                        <#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::set_error_at(&signal.#field_axs, path, error)
+                    })*
+                    Some(_) => Err(error),
+                }
+            )
+        }
+    };
+
+    let field_validations = fields
+        .iter()
+        .enumerate()
+        .map(|(i, spanned)| {
+            let field_ty = &field_tys[i];
+            let field_el_ty = &field_el_tys[i];
+            let field_ax = &field_axs[i];
+            let field_path_segment = &field_path_segments[i];
+            let validate_nested = quote!(
+                <#field_ty as #leptos_form_krate::FormField<#field_el_ty>>::validate(&signal.#field_ax, &#config_var_ident.#field_ax)
+            );
+            let validation = match spanned.validate.as_ref() {
+                Some(validate) => {
+                    let rules = validate.rules(&leptos_form_krate, field_ty)?;
+                    let validate_field = match validate.async_custom.is_some() {
+                        true => quote!(validate_async_field),
+                        false => quote!(validate_field),
+                    };
+                    quote!(#validate_nested.and(
+                        #leptos_form_krate::validation::#validate_field::<#field_ty, #field_el_ty>(&signal.#field_ax, &#config_var_ident.#field_ax, #rules)
+                    ))
+                }
+                None => validate_nested,
+            };
+            Ok(quote!(#validation #field_path_segment))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
     let wrapped_field_views = fields
         .iter()
         .enumerate()
@@ -570,7 +828,9 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
                 cache,
                 class: form_class,
                 field_changed_class,
+                field_errors,
                 map_submit,
+                multipart,
                 name: component_name,
                 on_error,
                 on_loading,
@@ -606,6 +866,16 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
                 }
             }
 
+            let multipart = multipart.unwrap_or_default();
+            if multipart {
+                if action.is_none() {
+                    return Err(Error::new(Span::call_site(), "multipart can only be specified on forms which specify an action"));
+                }
+                if map_submit.is_some() {
+                    return Err(Error::new(Span::call_site(), "component forms cannot specify multipart and map_submit attributes: multipart forms submit the entries of the form element rather than the parsed form data"));
+                }
+            }
+
             let map_submit = if action.as_ref().map(|x| x.is_path()).unwrap_or_default() {
                 match map_submit {
                     Some(MapSubmit::Defn(closure_defn)) => quote!(
@@ -628,10 +898,43 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
             };
 
             let parse_from_signal = quote!(#props_signal_ident.with(|props| <#component_ty as #leptos_form_krate::FormField<#leptos_krate::prelude::AnyView>>::try_from_signal(props.signal, &config)));
+            let validate_signal = quote!(#props_signal_ident.with(|props| {
+                // submitting counts as interacting with every field
+                <#component_ty as #leptos_form_krate::FormField<#leptos_krate::prelude::AnyView>>::touch(&props.signal);
+                <#component_ty as #leptos_form_krate::FormField<#leptos_krate::prelude::AnyView>>::validate(&props.signal, &config)
+            }));
 
             let _delete_from_cache_ident = delete_from_cache_ident.iter();
 
+            let multipart_attrs = multipart.then(|| quote!(method="post" enctype=String::from("multipart/form-data"))).into_iter();
+
             let (action_ident, tag_import, action_def, open_tag, close_tag, props_name) = match (on_submit.as_ref(), action) {
+                (None, Some(Action::Path { server_fn_path, arg, .. })) if multipart => {
+                    let action_ident = format_ident!("action");
+                    (
+                        Some(action_ident.clone()),
+                        quote!(use #leptos_router_krate::Form;),
+                        Some(quote!(
+                            let #action_ident = #leptos_krate::create_action(#leptos_form_krate::multipart_server_fn(#server_fn_path));
+                        )),
+                        quote!(<Form action="/" #(attr:id=#id)* #(attr:class=#class)* #(attr:style=#style)* on:submit=move |ev| {
+                            ev.prevent_default();
+                            if let Err(err) = #validate_signal {
+                                #parse_error_handler_ident(err);
+                                return;
+                            }
+                            if let Err(err) = #parse_from_signal {
+                                #parse_error_handler_ident(err);
+                                return;
+                            }
+
+                            #action_ident.dispatch(#leptos_form_krate::multipart_form_data(&ev));
+                            #(#_delete_from_cache_ident())*
+                        }>),
+                        quote!(</Form>),
+                        { let arg = format!("{arg}"); quote!(#leptos_krate::prelude::Oco::Borrowed(#arg)) },
+                    )
+                },
                 (None, Some(Action::Path { server_fn_path, arg, .. })) => {
                     let action_ident = format_ident!("action");
                     (
@@ -645,6 +948,10 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
                         )),
                         quote!(<Form action="/" #(attr:id=#id)* #(attr:class=#class)* #(attr:style=#style)* on:submit=move |ev| {
                             ev.prevent_default();
+                            if let Err(err) = #validate_signal {
+                                #parse_error_handler_ident(err);
+                                return;
+                            }
                             let #data_ident = match #parse_from_signal {
                                 Ok(parsed) => parsed,
                                 Err(err) => {
@@ -666,7 +973,12 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
                     None,
                     quote!(use #leptos_router_krate::Form;),
                     None,
-                    quote!(<Form action=#url #(attr:id=#id)* #(attr:class=#class)* #(attr:style=#style)*>),
+                    quote!(<Form action=#url #(#multipart_attrs)* #(attr:id=#id)* #(attr:class=#class)* #(attr:style=#style)* on:submit=move |ev| {
+                        if let Err(err) = #validate_signal {
+                            ev.prevent_default();
+                            #parse_error_handler_ident(err);
+                        }
+                    }>),
                     quote!(</Form>),
                     quote!(#leptos_krate::prelude::Oco::Borrowed("")),
                 ),
@@ -691,6 +1003,10 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
                             #(class=#class)*
                             #(style=#style)*
                             on:submit=move |#event_ident| {
+                                if let Err(err) = #validate_signal {
+                                    #parse_error_handler_ident(err);
+                                    return;
+                                }
                                 let #data_ident = match #parse_from_signal {
                                     Ok(parsed) => parsed,
                                     Err(err) => {
@@ -717,6 +1033,10 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
                         #(style=#style)*
                         on:submit=move |ev| {
                             ev.prevent_default();
+                            if let Err(err) = #validate_signal {
+                                #parse_error_handler_ident(err);
+                                return;
+                            }
                             #(#_delete_from_cache_ident())*
                         }
                     >),
@@ -727,6 +1047,10 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
             };
 
             let action_def = action_def.into_iter();
+            let submitting = match action_ident.as_ref() {
+                Some(action_ident) => quote!(Signal::derive(move || #action_ident.pending().get())),
+                None => quote!(Signal::stored(false)),
+            };
 
             let props_builder = quote!(
                 #leptos_form_krate::RenderProps::builder()
@@ -791,6 +1115,34 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
                 quote!()
             };
 
+            let field_errors_effect = if let Some(action_ident) = action_ident.as_ref().filter(|_| field_errors.unwrap_or_default()) {
+                quote!(
+                    #leptos_krate::Effect::new({
+                        let action_value = #action_ident.value();
+                        move |_| action_value.with(|value| {
+                            let Some(Err(err)) = value else { return };
+                            let mut unmatched = #leptos_form_krate::FormErrors::new();
+                            #props_signal_ident.with_untracked(|props| {
+                                for error in #leptos_form_krate::ToFormErrors::to_form_errors(err) {
+                                    let path = error.path().cloned().unwrap_or_default();
+                                    let error = error.with_path(Default::default());
+                                    if let Err(error) = <#ident as FormField<#leptos_krate::prelude::AnyView>>::set_error_at(&props.signal, &path.0, error) {
+                                        unmatched.push(error);
+                                    }
+                                }
+                                if !unmatched.is_empty() {
+                                    <#ident as FormField<#leptos_krate::prelude::AnyView>>::set_error(&props.signal, unmatched.into_result().err());
+                                }
+                            });
+                        })
+                    });
+                )
+            } else if field_errors.is_some() {
+                return Err(Error::new(Span::call_site(), "field_errors can only be specified on forms which specify an action using a server function"));
+            } else {
+                quote!()
+            };
+
             let form_submission_handler = if let Some(action_ident) = action_ident.as_ref() {
                 let error_view_ty = if on_error.is_some() { quote!() } else { quote!(error_view_ty={<::std::marker::PhantomData<#leptos_krate::prelude::AnyView> as Default>::default()}) };
                 let loading_view_ty = if on_loading.is_some() { quote!() } else { quote!(loading_view_ty={<::std::marker::PhantomData<#leptos_krate::prelude::AnyView> as Default>::default()}) };
@@ -903,10 +1255,12 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
                                         return;
                                     }
 
-                                    let value = #props_signal_ident.with(|props| {
+                                    let mut value = #props_signal_ident.with(|props| {
                                         let config = &props.config;
                                         #parse_from_signal
                                     }).unwrap_throw();
+                                    // secrets are never written to the cache
+                                    <#ident as #leptos_form_krate::FormField<#leptos_krate::prelude::AnyView>>::redact(&mut value);
                                     cache.set_item(&cache_key, &value).await.unwrap_throw();
                                 }
                             });
@@ -970,7 +1324,13 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
 
                         let #parse_error_handler_ident = |err: #leptos_form_krate::FormError| #leptos_krate::logging::debug_warn!("{err}");
 
+                        provide_context(#leptos_form_krate::components::FormStatus {
+                            has_errors: Signal::derive(move || #props_signal_ident.with(|props| <#ident as FormField<#leptos_krate::prelude::AnyView>>::has_errors(&props.signal))),
+                            submitting: #submitting,
+                        });
+
                         #optional_reset_on_success_effect
+                        #field_errors_effect
 
                         let ty = <::std::marker::PhantomData<(#ident, #leptos_krate::prelude::AnyView)> as Default>::default();
 
@@ -989,6 +1349,77 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
         })
         .transpose()?;
 
+    let rendered_form_error = render_error(&leptos_krate, form_error_handler.as_ref(), None, &error_ident)?;
+
+    let (validate_with_call, validate_with_fn, validate_with_on_change) = match validate_with.as_ref() {
+        Some(validate_with) => {
+            let validate_with = validate_with.deref();
+            let field_names = field_axs
+                .iter()
+                .map(|field_ax| field_ax.to_string())
+                .collect::<Vec<_>>();
+            let rule_checks = fields
+                .iter()
+                .zip(&field_axs)
+                .zip(&field_tys)
+                .filter_map(|((field, field_ax), field_ty)| {
+                    field.validate.as_ref().map(|validate| {
+                        let rules = validate.rules(&leptos_form_krate, field_ty)?;
+                        Ok(quote!((#rules)(&value.#field_ax).is_err()))
+                    })
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            (
+                quote!(Self::__validate_with(signal, #config_var_ident)?;),
+                quote!(
+                    impl #ident {
+                        /// Runs the struct-level validator once every field parses and satisfies its own rules,
+                        /// routing the returned errors onto the named fields and the form-level error slot.
+                        #[doc(hidden)]
+                        fn __validate_with(signal: &#signal_ty, #config_var_ident: &#config_ty) -> Result<(), #leptos_form_krate::FormError> {
+                            use #leptos_form_krate::FormField;
+                            use #leptos_krate::prelude::*;
+
+                            let Ok(value) = <Self as FormField<#leptos_krate::prelude::AnyView>>::try_from_signal(signal.clone(), #config_var_ident) else {
+                                return Ok(());
+                            };
+                            if false #(|| #rule_checks)* {
+                                return Ok(());
+                            }
+                            let errors = #validate_with(&value).err().unwrap_or_default();
+                            let prev_errors = signal.#errors_ax.get_untracked();
+                            #(
+                                if errors.fields.contains_key(#field_names) || prev_errors.fields.contains_key(#field_names) {
+                                    <#field_tys as FormField<#field_el_tys>>::set_error(&signal.#field_axs, errors.fields.get(#field_names).cloned());
+                                }
+                            )*
+                            let result = errors.to_result();
+                            signal.#errors_ax.set(errors);
+                            result
+                        }
+                    }
+                ),
+                quote!(
+                    let #props_ident = #leptos_form_krate::RenderProps {
+                        on_change: Some(#leptos_form_krate::FieldCallback::new({
+                            let signal = #props_ident.signal.clone();
+                            let config = #props_ident.config.clone();
+                            let on_change = #props_ident.on_change.clone();
+                            move || {
+                                let _ = #ident::__validate_with(&signal, &config);
+                                if let Some(on_change) = on_change.as_ref() {
+                                    on_change.call();
+                                }
+                            }
+                        })),
+                        ..#props_ident
+                    };
+                ),
+            )
+        }
+        None => (quote!(), quote!(), quote!()),
+    };
+
     let signal_struct_def = syn::ItemStruct {
         attrs: vec![syn::Attribute {
             pound_token: Default::default(),
@@ -1076,9 +1507,11 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
                 match initial {
                     Some(initial) => #signal_ty {
                         #(#field_axs: <#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::default_signal(&config.#field_axs, Some(initial.#field_axs)) ,)*
+                        #errors_ax: #new_errors_signal,
                     },
                     None => #signal_ty {
                         #(#field_axs: <#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::default_signal(&config.#field_axs, None) ,)*
+                        #errors_ax: #new_errors_signal,
                     },
                 }
             }
@@ -1091,15 +1524,23 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
                 match initial {
                     Some(initial) => #signal_ty {
                         #(#field_axs: <#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::into_signal(self.#field_axs, &#config_var_ident.#field_axs, Some(initial.#field_axs)) ,)*
+                        #errors_ax: #new_errors_signal,
                     },
                     None => #signal_ty {
                         #(#field_axs: <#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::into_signal(self.#field_axs, &#config_var_ident.#field_axs, None) ,)*
+                        #errors_ax: #new_errors_signal,
                     },
                 }
             }
             fn try_from_signal(signal: Self::Signal, #config_var_ident: &Self::Config) -> Result<Self, #leptos_form_krate::FormError> {
+                // every field is parsed so that all of their errors are returned, not just the first
+                let mut errors = #leptos_form_krate::FormErrors::new();
+                #(let #field_value_idents = errors.record(
+                    <#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::try_from_signal(signal.#field_axs, &#config_var_ident.#field_axs) #field_path_segments
+                );)*
+                #(let Some(#field_value_idents) = #field_value_idents else { return Err(errors.into()) };)*
                 Ok(#ident {
-                    #(#field_axs: <#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::try_from_signal(signal.#field_axs, &#config_var_ident.#field_axs)? ,)*
+                    #(#field_axs: #field_value_idents,)*
                 })
             }
             fn recurse(signal: &Self::Signal) {
@@ -1108,20 +1549,80 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
             fn reset_initial_value(signal: &Self::Signal) {
                 #(<#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::reset_initial_value(&signal.#field_axs);)*
             }
+            fn has_changed(signal: &Self::Signal) -> bool {
+                false #(||
+                    <#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::has_changed(&signal.#field_axs)
+                )*
+            }
+            fn validate(signal: &Self::Signal, #config_var_ident: &Self::Config) -> Result<(), #leptos_form_krate::FormError> {
+                // every field is validated so that all of their errors are displayed, not just the first
+                let mut errors = #leptos_form_krate::FormErrors::new();
+                #(errors.record(#field_validations);)*
+                errors.into_result()?;
+                #validate_with_call
+                Ok(())
+            }
+            fn set_error(signal: &Self::Signal, error: Option<#leptos_form_krate::FormError>) {
+                signal.#errors_ax.update(|errors| errors.form = error);
+            }
+            fn is_touched(signal: &Self::Signal) -> bool {
+                false #(||
+                    <#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::is_touched(&signal.#field_axs)
+                )*
+            }
+            fn touch(signal: &Self::Signal) {
+                #(<#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::touch(&signal.#field_axs);)*
+            }
+            fn redact(&mut self) {
+                #(<#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::redact(&mut self.#field_axs);)*
+            }
+            fn set_error_at(
+                signal: &Self::Signal,
+                path: &[#leptos_form_krate::PathSegment],
+                error: #leptos_form_krate::FormError,
+            ) -> Result<(), #leptos_form_krate::FormError> {
+                #set_error_at_body
+            }
+            fn collect_errors(signal: &Self::Signal, errors: &mut #leptos_form_krate::FormErrors) {
+                if let Some(error) = signal.#errors_ax.with(|errors| errors.form.clone()) {
+                    errors.push(error);
+                }
+                #(errors.extend(
+                    <#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::errors(&signal.#field_axs).into_iter() #field_error_paths
+                );)*
+            }
+            fn is_pending(signal: &Self::Signal) -> bool {
+                false #(||
+                    <#field_tys as #leptos_form_krate::FormField<#field_el_tys>>::is_pending(&signal.#field_axs)
+                )*
+            }
         }
 
+        #validate_with_fn
+
         impl #leptos_form_krate::FormComponent<#leptos_krate::prelude::AnyView> for #ident {
             #[allow(unused_imports)]
             fn render(#props_ident: #leptos_form_krate::RenderProps<Self::Signal, Self::Config>) -> impl #leptos_krate::IntoView {
                 use #leptos_form_krate::FormField;
                 use #leptos_krate::prelude::*;
 
+                #validate_with_on_change
+
                 #(#build_props)*
 
+                let _form_error = move || #props_ident.signal.#errors_ax.with(|errors| match errors.form.as_ref() {
+                    Some(form_error) => {
+                        let #error_ident = format!("{form_error}");
+                        #leptos_krate::IntoView::into_view(#rendered_form_error).into_any()
+                    },
+                    None => #leptos_krate::view!{}.into_any(),
+                });
+
                 #i18n
 
                 #leptos_krate::view! {
                     #rendered_fields
+                    {_form_error}
                 }
             }
         }
@@ -1132,105 +1633,754 @@ pub fn derive_form(tokens: TokenStream) -> Result<TokenStream, Error> {
     Ok(tokens)
 }
 
-fn field_el_ty(leptos_form_krate: &syn::Path, field: &FormField) -> syn::Type {
-    let ty = &field.ty;
-    field
-        .el
-        .clone()
-        .map(|x| x.0)
-        .unwrap_or_else(|| parse2(quote!(<#ty as #leptos_form_krate::DefaultHtmlElement>::El)).unwrap())
-}
-
-fn render_error(
+/// Struct attributes of an enum which are forwarded onto the structs generated for each of its variants.
+const FORWARDED_ENUM_ATTRS: &[&str] = &[
+    "error",
+    "field_class",
+    "field_style",
+    "i18n",
+    "internal",
+    "label",
+    "validate_on",
+];
+
+fn derive_enum_form(
+    ast: &syn::DeriveInput,
+    vis: &syn::Visibility,
+    ident: &syn::Ident,
+    variants: Vec<FormVariant>,
+    selector: VariantSelector,
+    leptos_form_krate: &syn::Path,
     leptos_krate: &syn::Path,
-    form_error_handler: Option<&SpannedValue<ErrorHandler>>,
-    field_error_handler: Option<&SpannedValue<ErrorHandler>>,
-    error_ident: &syn::Ident,
 ) -> Result<TokenStream, Error> {
-    use ErrorHandler as EH;
+    if variants.is_empty() {
+        return Err(Error::new(
+            ident.span(),
+            "cannot derive Form on an enum without variants",
+        ));
+    }
 
-    let form_eh = form_error_handler
-        .map(|x| Cow::Borrowed(x.deref()))
-        .unwrap_or(Cow::Owned(EH::Default));
-    let field_eh = field_error_handler
-        .map(|x| Cow::Borrowed(x.deref()))
-        .unwrap_or(Cow::Owned(EH::Default));
+    let signal_ident = format_ident!("__{ident}Signal");
+    let config_ident = format_ident!("__{ident}Config");
+    let discriminant_ident = format_ident!("discriminant");
+    let any_view: syn::Type = parse2(quote!(#leptos_krate::prelude::AnyView))?;
 
-    Ok(match (&*form_eh, &*field_eh) {
-        (EH::None, EH::Default) => quote!(#leptos_krate::view!{}),
-        (EH::Default, EH::Default) => quote!(#leptos_krate::view! { <span style="color: red;">{#error_ident}</span> }),
-        (EH::Component(component), EH::Default) => quote!(#leptos_krate::view! { <#component error=#error_ident /> }),
-        (EH::Container(Container { tag, id, class, style }), EH::Default) => {
-            let id = id.iter();
-            let class = class.iter();
-            let style = style.iter();
-            quote!(#leptos_krate::view! { <#tag #(id=#id)* #(class=#class)* #(style=#style)*>{#error_ident}</#tag> })
+    let forwarded_attrs = forwarded_form_attrs(&ast.attrs)?;
+
+    let default_variant = variants
+        .iter()
+        .find(|variant| variant.attrs.iter().any(|attr| attr.path().is_ident("default")))
+        .unwrap_or(&variants[0]);
+    let default_variant_name = default_variant.ident.to_string();
+
+    let mut variant_struct_defs = vec![];
+    let mut variant_structs = vec![];
+
+    // variants which contain fields are backed by a generated struct which derives Form, unit variants are not
+    let mut struct_variants = vec![];
+    let mut redact_arms = vec![];
+    for variant in &variants {
+        let variant_name = variant.ident.to_string();
+        let variant_field = format_ident!("{}", variant_name.to_case(Case::Snake));
+        if variant_field == discriminant_ident {
+            return Err(Error::new(
+                variant.ident.span(),
+                "variant name conflicts with the generated `discriminant` signal field",
+            ));
         }
-        (EH::Raw, EH::Default) => quote!({#error_ident}),
-        (_, EH::None) => quote!(#leptos_krate::view!{}),
-        (_, EH::Component(component)) => quote!(#leptos_krate::view! { <#component error=#error_ident /> }),
-        (_, EH::Container(Container { tag, id, class, style })) => {
-            let id = id.iter();
-            let class = class.iter();
-            let style = style.iter();
-            quote!(#leptos_krate::view! { <#tag #(id=#id)* #(class=#class)* #(style=#style)*>{#error_ident}</#tag> })
+        if variant.fields.style == Style::Unit {
+            continue;
         }
-        (_, EH::Raw) => quote!({#error_ident}),
-    })
-}
 
-fn wrap_field(
-    i: usize,
-    form_label: &FormLabel,
-    spanned: &SpannedValue<FormField>,
-    field_id_ident: &syn::Ident,
-    field_view_ident: &syn::Ident,
-    error_view_ident: &syn::Ident,
-    i18n_path: &TokenStream,
-) -> Result<TokenStream, Error> {
-    let field_view = quote!({#field_view_ident});
-    let error_view = quote!({#error_view_ident});
-    let field = spanned.deref();
-    let field_label = field.label.as_ref().unwrap_or(&FieldLabel::Default);
-    let (container, id, class, rename_all, style, value, i18n) = match (field_label, form_label) {
-        (FieldLabel::None, _) => return Ok(quote!(#field_view #error_view)),
-        (FieldLabel::Default, FormLabel::None) => return Ok(quote!(#field_view #error_view)),
-        (FieldLabel::Default, FormLabel::Default) => (None, None, None, None, None, None, None),
-        (
-            FieldLabel::Default,
-            FormLabel::Adjacent {
-                container,
-                id,
-                class,
-                rename_all,
-                style,
-            },
-        ) => (
-            Some(container.clone()),
-            id.as_ref(),
-            class.as_ref(),
-            rename_all.as_ref(),
-            style.as_ref(),
-            None,
-            None,
-        ),
-        (
-            FieldLabel::Default,
-            FormLabel::Wrap {
-                id,
-                class,
-                rename_all,
-                style,
-            },
-        ) => (
-            None,
-            id.as_ref(),
-            class.as_ref(),
-            rename_all.as_ref(),
-            style.as_ref(),
-            None,
-            None,
-        ),
+        let variant_struct_ident = format_ident!("__{ident}{}", variant.ident);
+        let is_newtype = variant.fields.style == Style::Tuple && variant.fields.len() == 1;
+
+        let field_bindings = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| field.ident.clone().unwrap_or_else(|| format_ident!("_{i}")))
+            .collect::<Vec<_>>();
+
+        let field_defs = variant.fields.iter().map(|field| {
+            let attrs = field
+                .attrs
+                .iter()
+                .filter(|attr| !attr.path().is_ident("form") && !attr.path().is_ident("serde"));
+            let field_ident = field.ident.iter();
+            let ty = &field.ty;
+            quote!(#(#attrs)* #vis #(#field_ident:)* #ty)
+        });
+        let field_inputs = variant
+            .fields
+            .iter()
+            .map(|field| {
+                let attrs = &field.attrs;
+                // newtype variants are rendered without a label by default, the enum's selector already names them
+                let label = (is_newtype && !has_form_attr(attrs, "label")?).then(|| quote!(#[form(label = "none")]));
+                let field_ident = field.ident.iter();
+                let ty = &field.ty;
+                Ok(quote!(#(#attrs)* #label #vis #(#field_ident:)* #ty))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let forwarded = (!forwarded_attrs.is_empty()).then(|| quote!(#[form(#(#forwarded_attrs),*)]));
+        let wrapper = is_newtype.then(|| quote!(#[form(wrapper)]));
+        let (struct_def, struct_input) = match variant.fields.style {
+            Style::Struct => (
+                quote!(#vis struct #variant_struct_ident { #(#field_defs,)* }),
+                quote!(#forwarded #vis struct #variant_struct_ident { #(#field_inputs,)* }),
+            ),
+            _ => (
+                quote!(#vis struct #variant_struct_ident(#(#field_defs,)*);),
+                quote!(#forwarded #wrapper #vis struct #variant_struct_ident(#(#field_inputs,)*);),
+            ),
+        };
+
+        variant_struct_defs.push(quote!(
+            #[doc(hidden)]
+            #struct_def
+        ));
+        variant_structs.push(derive_form(struct_input)?);
+
+        let variant_ident = &variant.ident;
+        let (pattern, construct) = match variant.fields.style {
+            Style::Struct => (
+                quote!(Self::#variant_ident { #(#field_bindings),* }),
+                quote!(#variant_struct_ident { #(#field_bindings),* }),
+            ),
+            _ => (
+                quote!(Self::#variant_ident(#(#field_bindings),*)),
+                quote!(#variant_struct_ident(#(#field_bindings),*)),
+            ),
+        };
+        let destructure = match variant.fields.style {
+            Style::Struct => quote!(#variant_struct_ident { #(#field_bindings),* }),
+            _ => quote!(#variant_struct_ident(#(#field_bindings),*)),
+        };
+
+        // the fields of the matched variant are redacted in place, as the fields of its generated struct would be
+        let field_redacts = variant
+            .fields
+            .iter()
+            .zip(&field_bindings)
+            .map(|(field, field_binding)| {
+                let field = FormField::from_field(field)?;
+                let ty = &field.ty;
+                let el_ty = field_el_ty(leptos_form_krate, &field);
+                Ok(quote!(<#ty as #leptos_form_krate::FormField<#el_ty>>::redact(#field_binding);))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        redact_arms.push(quote!(#pattern => { #(#field_redacts)* }));
+
+        struct_variants.push((
+            variant_name,
+            variant_field,
+            variant_struct_ident,
+            pattern,
+            construct,
+            destructure,
+        ));
+    }
+
+    let variant_names = variants
+        .iter()
+        .map(|variant| variant.ident.to_string())
+        .collect::<Vec<_>>();
+    let variant_labels = variants
+        .iter()
+        .map(|variant| {
+            variant
+                .label
+                .as_ref()
+                .map(|label| label.value())
+                .unwrap_or_else(|| variant.ident.to_string())
+        })
+        .collect::<Vec<_>>();
+    let variant_patterns = variants
+        .iter()
+        .map(|variant| {
+            let variant_ident = &variant.ident;
+            match variant.fields.style {
+                Style::Struct => quote!(Self::#variant_ident { .. }),
+                Style::Tuple => quote!(Self::#variant_ident(..)),
+                Style::Unit => quote!(Self::#variant_ident),
+            }
+        })
+        .collect::<Vec<_>>();
+    let unit_variant_names = variants
+        .iter()
+        .filter(|variant| variant.fields.style == Style::Unit)
+        .map(|variant| variant.ident.to_string())
+        .collect::<Vec<_>>();
+    let unit_variant_idents = variants
+        .iter()
+        .filter(|variant| variant.fields.style == Style::Unit)
+        .map(|variant| &variant.ident)
+        .collect::<Vec<_>>();
+
+    #[allow(clippy::type_complexity)]
+    let (struct_variant_names, variant_fields, variant_struct_idents, patterns, constructs, destructures): (
+        Vec<_>,
+        Vec<_>,
+        Vec<_>,
+        Vec<_>,
+        Vec<_>,
+        Vec<_>,
+    ) = struct_variants.into_iter().multiunzip();
+
+    let value_idents = variant_fields
+        .iter()
+        .map(|variant_field| format_ident!("_{variant_field}_value"))
+        .collect::<Vec<_>>();
+    let initial_idents = variant_fields
+        .iter()
+        .map(|variant_field| format_ident!("_{variant_field}_initial"))
+        .collect::<Vec<_>>();
+    let props_idents = variant_fields
+        .iter()
+        .map(|variant_field| format_ident!("_{variant_field}_props"))
+        .collect::<Vec<_>>();
+    let variant_ids = variant_fields
+        .iter()
+        .map(|variant_field| variant_field.to_string().to_case(Case::Kebab))
+        .collect::<Vec<_>>();
+
+    let form_field = quote!(#leptos_form_krate::FormField<#any_view>);
+
+    let split_initial = quote!(
+        let initial_discriminant = initial
+            .as_ref()
+            .map(|initial| match initial { #(#variant_patterns => #variant_names,)* }.to_string());
+        #(let mut #initial_idents = None;)*
+        if let Some(initial) = initial {
+            match initial {
+                #(#patterns => #initial_idents = Some(#constructs),)*
+                #(Self::#unit_variant_idents => {},)*
+            }
+        }
+    );
+
+    let selector_view = match selector {
+        VariantSelector::Select => quote!(
+            <select
+                id={#discriminant_ident.id.clone()}
+                name={#discriminant_ident.name.clone()}
+                class={class}
+                style={#discriminant_ident.style.clone()}
+                on:change=move |ev| signal.value.update(|value| *value = event_target_value(&ev))
+                prop:value={signal.value}
+            >
+                #(
+                    <option value=#variant_names selected={move || signal.value.with(|value| value == #variant_names)}>
+                        #variant_labels
+                    </option>
+                )*
+            </select>
+        ),
+        VariantSelector::Radio => quote!(
+            <div id={#discriminant_ident.id.clone()} class={class} style={#discriminant_ident.style.clone()}>
+                #(
+                    <label>
+                        <input
+                            type="radio"
+                            name={#discriminant_ident.name.clone()}
+                            value=#variant_names
+                            prop:checked={move || signal.value.with(|value| value == #variant_names)}
+                            on:change=move |_| signal.value.update(|value| *value = #variant_names.to_string())
+                        />
+                        #variant_labels
+                    </label>
+                )*
+            </div>
+        ),
+    };
+
+    // unit-only enums render as a plain selector without any variant sections
+    let variant_view = (!variant_fields.is_empty()).then(|| {
+        quote!(
+            {move || match signal.value.get().as_str() {
+                #(#struct_variant_names => {
+                    let ty = <::std::marker::PhantomData<(#variant_struct_idents, #any_view)> as Default>::default();
+                    #leptos_krate::view! { <FormField props=#props_idents.clone() ty=ty /> }.into_any()
+                },)*
+                _ => #leptos_krate::view! {}.into_any(),
+            }}
+        )
+    });
+
+    Ok(quote!(
+        #(#variant_struct_defs)*
+
+        #(#variant_structs)*
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct #signal_ident {
+            pub #discriminant_ident: #leptos_form_krate::FormFieldSignal<String>,
+            #(pub #variant_fields: <#variant_struct_idents as #form_field>::Signal,)*
+        }
+
+        #[derive(Clone, Debug, Default)]
+        pub struct #config_ident {
+            #(pub #variant_fields: <#variant_struct_idents as #form_field>::Config,)*
+        }
+
+        impl ::core::convert::AsRef<#signal_ident> for #signal_ident {
+            fn as_ref(&self) -> &Self {
+                self
+            }
+        }
+
+        impl ::core::convert::AsMut<#signal_ident> for #signal_ident {
+            fn as_mut(&mut self) -> &mut Self {
+                self
+            }
+        }
+
+        impl #leptos_form_krate::DefaultHtmlElement for #ident {
+            type El = #any_view;
+        }
+
+        impl #form_field for #ident {
+            type Config = #config_ident;
+            type Signal = #signal_ident;
+
+            #[allow(unused_variables)]
+            fn default_signal(config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                #split_initial
+                #signal_ident {
+                    #discriminant_ident: #leptos_form_krate::FormFieldSignal::new(#default_variant_name.to_string(), initial_discriminant),
+                    #(#variant_fields: <#variant_struct_idents as #form_field>::default_signal(&config.#variant_fields, #initial_idents),)*
+                }
+            }
+            fn is_default_value(signal: &Self::Signal) -> bool {
+                let discriminant = signal.#discriminant_ident.value.with_untracked(|value| value.clone());
+                if discriminant != #default_variant_name {
+                    return false;
+                }
+                match discriminant.as_str() {
+                    #(#struct_variant_names => <#variant_struct_idents as #form_field>::is_default_value(&signal.#variant_fields),)*
+                    _ => true,
+                }
+            }
+            #[allow(unused_variables)]
+            fn into_signal(self, config: &Self::Config, initial: Option<Self>) -> Self::Signal {
+                let discriminant = match &self { #(#variant_patterns => #variant_names,)* }.to_string();
+                #split_initial
+                #(let mut #value_idents = None;)*
+                match self {
+                    #(#patterns => #value_idents = Some(#constructs),)*
+                    #(Self::#unit_variant_idents => {},)*
+                }
+                #signal_ident {
+                    #discriminant_ident: #leptos_form_krate::FormFieldSignal::new(discriminant, initial_discriminant),
+                    #(#variant_fields: match #value_idents {
+                        Some(value) => <#variant_struct_idents as #form_field>::into_signal(value, &config.#variant_fields, #initial_idents),
+                        None => <#variant_struct_idents as #form_field>::default_signal(&config.#variant_fields, #initial_idents),
+                    },)*
+                }
+            }
+            #[allow(unused_variables)]
+            fn try_from_signal(signal: Self::Signal, config: &Self::Config) -> Result<Self, #leptos_form_krate::FormError> {
+                match signal.#discriminant_ident.value.with_untracked(|value| value.clone()).as_str() {
+                    #(#struct_variant_names => {
+                        let #destructures = <#variant_struct_idents as #form_field>::try_from_signal(signal.#variant_fields, &config.#variant_fields)?;
+                        Ok(#patterns)
+                    },)*
+                    #(#unit_variant_names => Ok(Self::#unit_variant_idents),)*
+                    value => Err(#leptos_form_krate::FormError::parse(format!("unknown variant `{value}`"))),
+                }
+            }
+            fn recurse(signal: &Self::Signal) {
+                signal.#discriminant_ident.value.with(|_| {});
+                #(<#variant_struct_idents as #form_field>::recurse(&signal.#variant_fields);)*
+            }
+            fn reset_initial_value(signal: &Self::Signal) {
+                signal.#discriminant_ident.value.with_untracked(|value| {
+                    signal.#discriminant_ident.initial.update(|initial| *initial = Some(value.clone()))
+                });
+                #(<#variant_struct_idents as #form_field>::reset_initial_value(&signal.#variant_fields);)*
+            }
+            fn has_changed(signal: &Self::Signal) -> bool {
+                signal.#discriminant_ident.has_changed_tracked()
+                    || match signal.#discriminant_ident.value.with(|value| value.clone()).as_str() {
+                        #(#struct_variant_names => <#variant_struct_idents as #form_field>::has_changed(&signal.#variant_fields),)*
+                        _ => false,
+                    }
+            }
+            #[allow(unused_variables)]
+            fn validate(signal: &Self::Signal, config: &Self::Config) -> Result<(), #leptos_form_krate::FormError> {
+                match signal.#discriminant_ident.value.with_untracked(|value| value.clone()).as_str() {
+                    #(#struct_variant_names => <#variant_struct_idents as #form_field>::validate(&signal.#variant_fields, &config.#variant_fields),)*
+                    _ => Ok(()),
+                }
+            }
+            fn with_error<O>(signal: &Self::Signal, f: impl FnOnce(Option<&#leptos_form_krate::FormError>) -> O) -> O {
+                if signal.#discriminant_ident.error.with(|error| error.is_some()) {
+                    return signal.#discriminant_ident.error.with(|error| f(error.as_ref()));
+                }
+                match signal.#discriminant_ident.value.with(|value| value.clone()).as_str() {
+                    #(#struct_variant_names => <#variant_struct_idents as #form_field>::with_error(&signal.#variant_fields, f),)*
+                    _ => f(None),
+                }
+            }
+            fn set_error(signal: &Self::Signal, error: Option<#leptos_form_krate::FormError>) {
+                signal.#discriminant_ident.error.set(error)
+            }
+            fn is_pending(signal: &Self::Signal) -> bool {
+                match signal.#discriminant_ident.value.with(|value| value.clone()).as_str() {
+                    #(#struct_variant_names => <#variant_struct_idents as #form_field>::is_pending(&signal.#variant_fields),)*
+                    _ => false,
+                }
+            }
+            fn is_touched(signal: &Self::Signal) -> bool {
+                signal.#discriminant_ident.touched.get()
+                    || match signal.#discriminant_ident.value.with(|value| value.clone()).as_str() {
+                        #(#struct_variant_names => <#variant_struct_idents as #form_field>::is_touched(&signal.#variant_fields),)*
+                        _ => false,
+                    }
+            }
+            fn touch(signal: &Self::Signal) {
+                signal.#discriminant_ident.touched.set(true);
+                match signal.#discriminant_ident.value.with_untracked(|value| value.clone()).as_str() {
+                    #(#struct_variant_names => <#variant_struct_idents as #form_field>::touch(&signal.#variant_fields),)*
+                    _ => {}
+                }
+            }
+            fn redact(&mut self) {
+                match self {
+                    #(#redact_arms)*
+                    #(Self::#unit_variant_idents => {},)*
+                }
+            }
+            fn set_error_at(
+                signal: &Self::Signal,
+                path: &[#leptos_form_krate::PathSegment],
+                error: #leptos_form_krate::FormError,
+            ) -> Result<(), #leptos_form_krate::FormError> {
+                match (signal.#discriminant_ident.value.with_untracked(|value| value.clone()).as_str(), path.is_empty()) {
+                    (_, true) => {
+                        signal.#discriminant_ident.error.set(Some(error));
+                        Ok(())
+                    }
+                    #((#struct_variant_names, false) => <#variant_struct_idents as #form_field>::set_error_at(&signal.#variant_fields, path, error),)*
+                    _ => Err(error),
+                }
+            }
+            fn collect_errors(signal: &Self::Signal, errors: &mut #leptos_form_krate::FormErrors) {
+                if let Some(error) = signal.#discriminant_ident.error.with(|error| error.clone()) {
+                    errors.push(error);
+                }
+                match signal.#discriminant_ident.value.with(|value| value.clone()).as_str() {
+                    #(#struct_variant_names => <#variant_struct_idents as #form_field>::collect_errors(&signal.#variant_fields, errors),)*
+                    _ => {}
+                }
+            }
+        }
+
+        impl #leptos_form_krate::FormComponent<#any_view> for #ident {
+            #[allow(unused_imports)]
+            fn render(props: #leptos_form_krate::RenderProps<Self::Signal, Self::Config>) -> impl #leptos_krate::IntoView {
+                use #leptos_form_krate::FormField;
+                use #leptos_krate::prelude::*;
+
+                let #discriminant_ident = #leptos_form_krate::RenderProps::builder()
+                    .id(props.id.clone())
+                    .name(#leptos_form_krate::format_form_name(props.name.as_ref(), "discriminant"))
+                    .class(props.class.clone())
+                    .style(props.style.clone())
+                    .field_changed_class(props.field_changed_class.clone())
+                    .signal(props.signal.#discriminant_ident)
+                    .config(())
+                    .build();
+                let signal = #discriminant_ident.signal;
+                let class = #discriminant_ident.class_signal();
+
+                #(
+                    let #props_idents = #leptos_form_krate::RenderProps::builder()
+                        .id(#leptos_form_krate::format_form_id(props.id.as_ref(), #variant_ids))
+                        .name(#leptos_form_krate::format_form_name(props.name.as_ref(), #struct_variant_names))
+                        .field_changed_class(props.field_changed_class.clone())
+                        .on_change(props.on_change.clone())
+                        .validate_on(props.validate_on)
+                        .signal(props.signal.#variant_fields)
+                        .config(props.config.#variant_fields.clone())
+                        .build();
+                )*
+
+                #leptos_krate::view! {
+                    #selector_view
+                    #variant_view
+                }
+            }
+        }
+    ))
+}
+
+/// Collects the nested items of all `#[form(..)]` attributes whose path is one of [`FORWARDED_ENUM_ATTRS`].
+fn forwarded_form_attrs(attrs: &[syn::Attribute]) -> Result<Vec<NestedMeta>, Error> {
+    let mut forwarded = vec![];
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("form")) {
+        let items = NestedMeta::parse_meta_list(attr.meta.require_list()?.tokens.clone())?;
+        forwarded.extend(items.into_iter().filter(|item| match item {
+            NestedMeta::Meta(meta) => FORWARDED_ENUM_ATTRS.iter().any(|name| meta.path().is_ident(name)),
+            NestedMeta::Lit(_) => false,
+        }));
+    }
+    Ok(forwarded)
+}
+
+fn has_form_attr(attrs: &[syn::Attribute], name: &str) -> Result<bool, Error> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("form")) {
+        let items = NestedMeta::parse_meta_list(attr.meta.require_list()?.tokens.clone())?;
+        if items
+            .iter()
+            .any(|item| matches!(item, NestedMeta::Meta(meta) if meta.path().is_ident(name)))
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Native html attributes of a field's input derived from its attributes and validation rules.
+fn input_attrs(leptos_form_krate: &syn::Path, leptos_krate: &syn::Path, field: &FormField) -> TokenStream {
+    let oco = StringExpr::with_oco(leptos_krate);
+    let validate = field.validate.clone().unwrap_or_default();
+
+    let mut attrs = vec![];
+    if validate.required.unwrap_or_default() {
+        attrs.push(quote!(required: true));
+    }
+    if let Some(min_len) = validate.min_len.as_ref() {
+        attrs.push(quote!(min_length: Some(#min_len)));
+    }
+    if let Some(max_len) = validate.max_len.as_ref() {
+        attrs.push(quote!(max_length: Some(#max_len)));
+    }
+    if let Some(pattern) = validate.pattern.as_ref() {
+        attrs.push(quote!(pattern: Some(#leptos_krate::prelude::Oco::Borrowed(#pattern))));
+    }
+    if let Some(step) = field.step.clone() {
+        let step = oco(step);
+        attrs.push(quote!(step: Some(#step)));
+    }
+    let input_mode = match field.inputmode.clone() {
+        Some(input_mode) => Some(oco(input_mode)),
+        None => validate
+            .email
+            .unwrap_or_default()
+            .then(|| quote!(#leptos_krate::prelude::Oco::Borrowed("email"))),
+    };
+    if let Some(input_mode) = input_mode {
+        attrs.push(quote!(input_mode: Some(#input_mode)));
+    }
+    if let Some(autocomplete) = field.autocomplete.clone() {
+        let autocomplete = oco(autocomplete);
+        attrs.push(quote!(autocomplete: Some(#autocomplete)));
+    }
+
+    match attrs.is_empty() {
+        true => quote!(),
+        false => quote!(.input_attrs(#leptos_form_krate::InputAttrs { #(#attrs,)* ..Default::default() })),
+    }
+}
+
+impl ValidateOn {
+    fn tokens(&self, leptos_form_krate: &syn::Path) -> TokenStream {
+        match self {
+            Self::Input => quote!(#leptos_form_krate::ValidateOn::Input),
+            Self::Change => quote!(#leptos_form_krate::ValidateOn::Change),
+            Self::Blur => quote!(#leptos_form_krate::ValidateOn::Blur),
+            Self::Submit => quote!(#leptos_form_krate::ValidateOn::Submit),
+        }
+    }
+}
+
+/// Compiles `pattern` with the same anchoring as `leptos_form::validation::pattern` so that it cannot fail at runtime.
+#[cfg(feature = "regex")]
+fn check_pattern(pattern: &syn::LitStr) -> Result<(), Error> {
+    match ::regex::Regex::new(&format!("^(?:{})$", pattern.value())) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Error::new(pattern.span(), format!("invalid `pattern`: {err}"))),
+    }
+}
+
+#[cfg(not(feature = "regex"))]
+fn check_pattern(pattern: &syn::LitStr) -> Result<(), Error> {
+    Err(Error::new(
+        pattern.span(),
+        "`pattern` requires the `regex` feature of leptos_form",
+    ))
+}
+
+impl Validate {
+    /// A closure which checks every synchronous rule against a reference to the field's parsed value.
+    fn rules(&self, leptos_form_krate: &syn::Path, field_ty: &syn::Type) -> Result<TokenStream, Error> {
+        if let (Some(debounce_ms), None) = (self.debounce_ms.as_ref(), self.async_custom.as_ref()) {
+            return Err(Error::new(
+                debounce_ms.span(),
+                "`debounce_ms` can only be used with `async_custom`",
+            ));
+        }
+        let validation = quote!(#leptos_form_krate::validation);
+        let mut rules = vec![];
+        if self.required.unwrap_or_default() {
+            rules.push(quote!(#validation::required(value)?;));
+        }
+        if let Some(min_len) = self.min_len.as_ref() {
+            rules.push(quote!(#validation::min_len(value, #min_len)?;));
+        }
+        if let Some(max_len) = self.max_len.as_ref() {
+            rules.push(quote!(#validation::max_len(value, #max_len)?;));
+        }
+        if let Some(range) = self.range.as_ref() {
+            if range.min.is_none() && range.max.is_none() {
+                return Err(Error::new(
+                    range.span(),
+                    "`range` requires at least one of `min` or `max`",
+                ));
+            }
+            let min = range
+                .min
+                .as_ref()
+                .map(|min| quote!(Some(#min)))
+                .unwrap_or_else(|| quote!(None));
+            let max = range
+                .max
+                .as_ref()
+                .map(|max| quote!(Some(#max)))
+                .unwrap_or_else(|| quote!(None));
+            rules.push(quote!(#validation::range(value, #min, #max)?;));
+        }
+        if let Some(pattern) = self.pattern.as_ref() {
+            check_pattern(pattern)?;
+            rules.push(quote!(#validation::pattern(value, #pattern)?;));
+        }
+        if self.email.unwrap_or_default() {
+            rules.push(quote!(#validation::email(value)?;));
+        }
+        if let Some(custom) = self.custom.as_ref() {
+            rules.push(quote!(#custom(value)?;));
+        }
+        Ok(
+            quote!(|value: &#field_ty| -> Result<(), #leptos_form_krate::FormError> {
+                #(#rules)*
+                Ok(())
+            }),
+        )
+    }
+
+    /// Debounce period of the async validator in milliseconds.
+    fn debounce_ms(&self) -> Result<u64, Error> {
+        Ok(self
+            .debounce_ms
+            .as_ref()
+            .map(|debounce_ms| debounce_ms.base10_parse::<u64>())
+            .transpose()?
+            .unwrap_or(500))
+    }
+}
+
+fn field_el_ty(leptos_form_krate: &syn::Path, field: &FormField) -> syn::Type {
+    let ty = &field.ty;
+    field
+        .el
+        .clone()
+        .map(|x| x.0)
+        .unwrap_or_else(|| parse2(quote!(<#ty as #leptos_form_krate::DefaultHtmlElement>::El)).unwrap())
+}
+
+fn render_error(
+    leptos_krate: &syn::Path,
+    form_error_handler: Option<&SpannedValue<ErrorHandler>>,
+    field_error_handler: Option<&SpannedValue<ErrorHandler>>,
+    error_ident: &syn::Ident,
+) -> Result<TokenStream, Error> {
+    use ErrorHandler as EH;
+
+    let form_eh = form_error_handler
+        .map(|x| Cow::Borrowed(x.deref()))
+        .unwrap_or(Cow::Owned(EH::Default));
+    let field_eh = field_error_handler
+        .map(|x| Cow::Borrowed(x.deref()))
+        .unwrap_or(Cow::Owned(EH::Default));
+
+    Ok(match (&*form_eh, &*field_eh) {
+        (EH::None, EH::Default) => quote!(#leptos_krate::view!{}),
+        (EH::Default, EH::Default) => quote!(#leptos_krate::view! { <span style="color: red;">{#error_ident}</span> }),
+        (EH::Component(component), EH::Default) => quote!(#leptos_krate::view! { <#component error=#error_ident /> }),
+        (EH::Container(Container { tag, id, class, style }), EH::Default) => {
+            let id = id.iter();
+            let class = class.iter();
+            let style = style.iter();
+            quote!(#leptos_krate::view! { <#tag #(id=#id)* #(class=#class)* #(style=#style)*>{#error_ident}</#tag> })
+        }
+        (EH::Raw, EH::Default) => quote!({#error_ident}),
+        (_, EH::None) => quote!(#leptos_krate::view!{}),
+        (_, EH::Component(component)) => quote!(#leptos_krate::view! { <#component error=#error_ident /> }),
+        (_, EH::Container(Container { tag, id, class, style })) => {
+            let id = id.iter();
+            let class = class.iter();
+            let style = style.iter();
+            quote!(#leptos_krate::view! { <#tag #(id=#id)* #(class=#class)* #(style=#style)*>{#error_ident}</#tag> })
+        }
+        (_, EH::Raw) => quote!({#error_ident}),
+    })
+}
+
+fn wrap_field(
+    i: usize,
+    form_label: &FormLabel,
+    spanned: &SpannedValue<FormField>,
+    field_id_ident: &syn::Ident,
+    field_view_ident: &syn::Ident,
+    error_view_ident: &syn::Ident,
+    i18n_path: &TokenStream,
+) -> Result<TokenStream, Error> {
+    let field_view = quote!({#field_view_ident});
+    let error_view = quote!({#error_view_ident});
+    let field = spanned.deref();
+    let field_label = field.label.as_ref().unwrap_or(&FieldLabel::Default);
+    let (container, id, class, rename_all, style, value, i18n) = match (field_label, form_label) {
+        (FieldLabel::None, _) => return Ok(quote!(#field_view #error_view)),
+        (FieldLabel::Default, FormLabel::None) => return Ok(quote!(#field_view #error_view)),
+        (FieldLabel::Default, FormLabel::Default) => (None, None, None, None, None, None, None),
+        (
+            FieldLabel::Default,
+            FormLabel::Adjacent {
+                container,
+                id,
+                class,
+                rename_all,
+                style,
+            },
+        ) => (
+            Some(container.clone()),
+            id.as_ref(),
+            class.as_ref(),
+            rename_all.as_ref(),
+            style.as_ref(),
+            None,
+            None,
+        ),
+        (
+            FieldLabel::Default,
+            FormLabel::Wrap {
+                id,
+                class,
+                rename_all,
+                style,
+            },
+        ) => (
+            None,
+            id.as_ref(),
+            class.as_ref(),
+            rename_all.as_ref(),
+            style.as_ref(),
+            None,
+            None,
+        ),
         (
             FieldLabel::Adjacent {
                 container,
@@ -1951,225 +3101,543 @@ mod test {
             }
         );
 
-        let err = expect_err(derive_form(input));
-        assert_eq!(
-            "action must be specified with an endpoint string literal or a server fn call expression",
-            format!("{err}")
-        );
+        let err = expect_err(derive_form(input));
+        assert_eq!(
+            "action must be specified with an endpoint string literal or a server fn call expression",
+            format!("{err}")
+        );
+    }
+
+    #[test]
+    fn field_element_rejects_path_meta() {
+        let input = quote!(
+            #[derive(Form)]
+            pub struct MyFormData {
+                #[form(el)]
+                pub id: Uuid,
+            }
+        );
+
+        let err = expect_err(derive_form(input));
+
+        assert_eq!(
+            "el type unspecified, use `el(leptos::html::HtmlElement<..>)`",
+            format!("{err}")
+        );
+    }
+
+    #[test]
+    fn field_element_rejects_name_value_meta() {
+        let input = quote!(
+            #[derive(Form)]
+            pub struct MyFormData {
+                #[form(el = MyElement)]
+                pub id: Uuid,
+            }
+        );
+
+        let err = expect_err(derive_form(input));
+
+        assert_eq!(
+            "el must be specified using parentheses like `el(leptos::html::HtmlElement<..>)`",
+            format!("{err}")
+        );
+    }
+
+    #[test]
+    fn field_element_rejects_non_types() {
+        let input = quote!(
+            #[derive(Form)]
+            pub struct MyFormData {
+                #[form(el(1+1))]
+                pub id: Uuid,
+            }
+        );
+
+        let err = expect_err(derive_form(input));
+
+        // less than ideal error message but cannot control that darling attempts to parse the `el = TokenStream` and fails
+        // prior to calling from_meta
+        assert_eq!("expected valid rust type", format!("{err}"));
+    }
+
+    #[test]
+    fn field_element_accepts_valid_types_when_parenthesized() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            pub struct MyFormData {
+                #[form(el(::leptos::HtmlElement<::leptos::html::Input>))]
+                pub id: Uuid,
+            }
+        );
+
+        derive_form(input)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn group_item_rejects_non_container() {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(groups(1))]
+            pub struct MyFormData {
+                pub id: Uuid,
+            }
+        );
+
+        let err = expect_err(derive_form(input));
+
+        assert_eq!("expected argument of the form `container(..)`", format!("{err}"));
+    }
+
+    #[test]
+    fn group_item_accepts_container() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(groups(container(tag = "div")))]
+            pub struct MyFormData {
+                pub id: Uuid,
+            }
+        );
+
+        derive_form(input)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn field_group_out_of_range_is_rejected() {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(groups(container(tag = "div")))]
+            pub struct MyFormData {
+                #[form(group = 0)]
+                pub id: Uuid,
+                #[form(group = 2)]
+                pub name: Uuid,
+            }
+        );
+
+        let err = expect_err(derive_form(input));
+
+        assert_eq!("group index out of range", format!("{err}"));
+    }
+
+    #[test]
+    #[cfg_attr(feature = "i18n", ignore)]
+    fn tuple_component_unnamed_is_rejected() {
+        let expected_err_msg = r#"deriving Form for a tuple struct with `component` specified additionally requires a component name, consider using `component(name = "MyFormData_")`; note that "MyFormData" cannot be used as the component name because MyFormData is already an item defined in the function namespace and "_MyFormData" should not be used because of leptos name transformations"#;
+
+        let input = quote!(
+            #[derive(Form)]
+            #[form(component(action = "foo"))]
+            pub struct MyFormData(Uuid);
+        );
+
+        let err = expect_err(derive_form(input));
+
+        assert_eq!(expected_err_msg, format!("{err}"));
+    }
+
+    #[test]
+    #[cfg_attr(feature = "i18n", ignore)]
+    fn tuple_component_same_name_is_rejected() {
+        let expected_err_msg = r#"deriving Form for a tuple struct with `component` specified additionally requires a component name, consider using `component(name = "MyFormData_")`; note that "MyFormData" cannot be used as the component name because MyFormData is already an item defined in the function namespace and "_MyFormData" should not be used because of leptos name transformations"#;
+
+        let input = quote!(
+            #[derive(Form)]
+            #[form(component(action = "foo", name = MyFormData))]
+            pub struct MyFormData(Uuid);
+        );
+
+        let err = expect_err(derive_form(input));
+
+        assert_eq!(expected_err_msg, format!("{err}"));
+    }
+
+    #[test]
+    #[cfg_attr(feature = "i18n", ignore)]
+    fn tuple_component_same_name_preceded_with_underscore_is_rejected() {
+        let expected_err_msg = r#"deriving Form for a tuple struct with `component` specified additionally requires a component name, consider using `component(name = "MyFormData_")`; note that "MyFormData" cannot be used as the component name because MyFormData is already an item defined in the function namespace and "_MyFormData" should not be used because of leptos name transformations"#;
+
+        let input = quote!(
+            #[derive(Form)]
+            #[form(component(action = "foo", name = _MyFormData))]
+            pub struct MyFormData(Uuid);
+        );
+
+        let err = expect_err(derive_form(input));
+
+        assert_eq!(expected_err_msg, format!("{err}"));
+    }
+
+    #[test]
+    #[cfg_attr(feature = "i18n", ignore)]
+    fn tuple_component_name_valid() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(component(action = "foo", name = Test))]
+            pub struct MyFormData(Uuid);
+        );
+
+        derive_form(input)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn map_submit_rejects_non_path_or_closure() {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(component(
+                action = "foo",
+                map_submit = 1 + 1,
+            ))]
+            pub struct MyFormData {
+                pub id: Uuid,
+            }
+        );
+
+        let err = expect_err(derive_form(input));
+
+        assert_eq!("expected a path or a closure", format!("{err}"));
+    }
+
+    #[test]
+    fn map_submit_accepts_path() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(component(
+                action = "foo",
+                map_submit = map_my_submit,
+            ))]
+            pub struct MyFormData {
+                pub id: Uuid,
+            }
+        );
+
+        derive_form(input)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn form_can_be_derived_on_enums() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(selector = "radio", label(wrap(rename_all = "Title Case")))]
+            pub enum PaymentMethod {
+                Card {
+                    number: String,
+                    #[form(label = "none")]
+                    cvv: String,
+                },
+                #[form(label = "PayPal")]
+                Paypal(String),
+                #[default]
+                Cash,
+            }
+        );
+
+        let output = pretty(cleanup(&derive_form(input)?))?;
+
+        assert!(output.contains("pub struct __PaymentMethodCard {"));
+        assert!(output.contains("pub struct __PaymentMethodPaypal(pub String);"));
+        assert!(!output.contains("__PaymentMethodCash"));
+        assert!(output.contains("pub discriminant: ::leptos_form::FormFieldSignal<String>,"));
+        assert!(output.contains("\"Cash\".to_string()"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn form_can_be_derived_on_unit_only_enums() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            pub enum ShippingOption {
+                Standard,
+                Express,
+            }
+        );
+
+        let output = cleanup(&derive_form(input)?);
+
+        assert!(output.contains("< select"));
+        assert!(!output.contains("pub struct __ShippingOptionStandard"));
+
+        Ok(())
     }
 
     #[test]
-    fn field_element_rejects_path_meta() {
+    fn enums_reject_component_attribute() {
         let input = quote!(
             #[derive(Form)]
-            pub struct MyFormData {
-                #[form(el)]
-                pub id: Uuid,
+            #[form(component)]
+            pub enum ShippingOption {
+                Standard,
+                Express,
             }
         );
 
         let err = expect_err(derive_form(input));
 
         assert_eq!(
-            "el type unspecified, use `el(leptos::html::HtmlElement<..>)`",
+            "`component` cannot be derived on an enum, consider wrapping the enum in a struct which derives Form",
             format!("{err}")
         );
     }
 
     #[test]
-    fn field_element_rejects_name_value_meta() {
+    fn structs_reject_selector_attribute() {
         let input = quote!(
             #[derive(Form)]
+            #[form(selector = "radio")]
             pub struct MyFormData {
-                #[form(el = MyElement)]
                 pub id: Uuid,
             }
         );
 
         let err = expect_err(derive_form(input));
 
-        assert_eq!(
-            "el must be specified using parentheses like `el(leptos::html::HtmlElement<..>)`",
-            format!("{err}")
-        );
+        assert_eq!("`selector` can only be used on enums", format!("{err}"));
     }
 
     #[test]
-    fn field_element_rejects_non_types() {
+    fn field_validation_rules_are_generated() -> Result<(), Error> {
         let input = quote!(
             #[derive(Form)]
-            pub struct MyFormData {
-                #[form(el(1+1))]
-                pub id: Uuid,
+            #[form(component)]
+            pub struct SignUp {
+                #[form(validate(required, min_len = 3, max_len = 64))]
+                pub name: String,
+                #[form(validate(email, custom = check_domain))]
+                pub email: String,
+                #[form(validate(range(min = 18)))]
+                pub age: Option<u8>,
             }
         );
 
-        let err = expect_err(derive_form(input));
+        let output = cleanup(&derive_form(input)?);
 
-        // less than ideal error message but cannot control that darling attempts to parse the `el = TokenStream` and fails
-        // prior to calling from_meta
-        assert_eq!("expected valid rust type", format!("{err}"));
+        assert!(output.contains("validation :: required (value) ?"));
+        assert!(output.contains("validation :: min_len (value , 3) ?"));
+        assert!(output.contains("validation :: max_len (value , 64) ?"));
+        assert!(output.contains("validation :: email (value) ?"));
+        assert!(output.contains("check_domain (value) ?"));
+        assert!(output.contains("validation :: range (value , Some (18) , None) ?"));
+        assert!(output.contains("fn validate (signal : & Self :: Signal , config : & Self :: Config)"));
+
+        Ok(())
     }
 
     #[test]
-    fn field_element_accepts_valid_types_when_parenthesized() -> Result<(), Error> {
+    fn async_field_validation_is_generated() -> Result<(), Error> {
         let input = quote!(
             #[derive(Form)]
-            pub struct MyFormData {
-                #[form(el(::leptos::HtmlElement<::leptos::html::Input>))]
-                pub id: Uuid,
+            #[form(component)]
+            pub struct SignUp {
+                #[form(validate(required, async_custom = username_available, debounce_ms = 250))]
+                pub username: String,
             }
         );
 
-        derive_form(input)?;
+        let output = cleanup(&derive_form(input)?);
+
+        assert!(output.contains("validation :: AsyncValidator :: < String , < String as :: leptos_form :: DefaultHtmlElement > :: El > :: new"));
+        assert!(output.contains("250u64"));
+        assert!(output.contains("username_available ,"));
+        assert!(output.contains("async_validator . schedule ()"));
+        assert!(output.contains("async_validator . restore ()"));
+        assert!(output.contains("validation :: validate_async_field :: <"));
 
         Ok(())
     }
 
     #[test]
-    fn group_item_rejects_non_container() {
+    fn field_validation_debounce_requires_async_custom() {
         let input = quote!(
             #[derive(Form)]
-            #[form(groups(1))]
-            pub struct MyFormData {
-                pub id: Uuid,
+            pub struct SignUp {
+                #[form(validate(required, debounce_ms = 250))]
+                pub username: String,
             }
         );
 
         let err = expect_err(derive_form(input));
 
-        assert_eq!("expected argument of the form `container(..)`", format!("{err}"));
+        assert_eq!("`debounce_ms` can only be used with `async_custom`", format!("{err}"));
     }
 
     #[test]
-    fn group_item_accepts_container() -> Result<(), Error> {
+    #[cfg(feature = "regex")]
+    fn field_validation_pattern_is_mirrored_onto_the_input() -> Result<(), Error> {
         let input = quote!(
             #[derive(Form)]
-            #[form(groups(container(tag = "div")))]
-            pub struct MyFormData {
-                pub id: Uuid,
+            pub struct SignUp {
+                #[form(validate(pattern = "[a-z]+"))]
+                pub name: String,
             }
         );
 
-        derive_form(input)?;
+        let output = cleanup(&derive_form(input)?);
+
+        assert!(output.contains(
+            "pattern : Some (:: leptos_form :: internal :: leptos :: prelude :: Oco :: Borrowed (\"[a-z]+\"))"
+        ));
+        assert!(output.contains("validation :: pattern (value , \"[a-z]+\") ?"));
 
         Ok(())
     }
 
     #[test]
-    fn field_group_out_of_range_is_rejected() {
+    #[cfg(not(feature = "regex"))]
+    fn field_validation_pattern_requires_the_regex_feature() {
         let input = quote!(
             #[derive(Form)]
-            #[form(groups(container(tag = "div")))]
-            pub struct MyFormData {
-                #[form(group = 0)]
-                pub id: Uuid,
-                #[form(group = 2)]
-                pub name: Uuid,
+            pub struct SignUp {
+                #[form(validate(pattern = "[a-z]+"))]
+                pub name: String,
             }
         );
 
         let err = expect_err(derive_form(input));
 
-        assert_eq!("group index out of range", format!("{err}"));
+        assert_eq!(
+            "`pattern` requires the `regex` feature of leptos_form",
+            format!("{err}")
+        );
     }
 
     #[test]
-    #[cfg_attr(feature = "i18n", ignore)]
-    fn tuple_component_unnamed_is_rejected() {
-        let expected_err_msg = r#"deriving Form for a tuple struct with `component` specified additionally requires a component name, consider using `component(name = "MyFormData_")`; note that "MyFormData" cannot be used as the component name because MyFormData is already an item defined in the function namespace and "_MyFormData" should not be used because of leptos name transformations"#;
-
+    #[cfg(feature = "regex")]
+    fn field_validation_rejects_invalid_pattern() {
         let input = quote!(
             #[derive(Form)]
-            #[form(component(action = "foo"))]
-            pub struct MyFormData(Uuid);
+            pub struct SignUp {
+                #[form(validate(pattern = "[a-z"))]
+                pub username: String,
+            }
         );
 
         let err = expect_err(derive_form(input));
 
-        assert_eq!(expected_err_msg, format!("{err}"));
+        assert!(format!("{err}").starts_with("invalid `pattern`: "));
     }
 
     #[test]
-    #[cfg_attr(feature = "i18n", ignore)]
-    fn tuple_component_same_name_is_rejected() {
-        let expected_err_msg = r#"deriving Form for a tuple struct with `component` specified additionally requires a component name, consider using `component(name = "MyFormData_")`; note that "MyFormData" cannot be used as the component name because MyFormData is already an item defined in the function namespace and "_MyFormData" should not be used because of leptos name transformations"#;
-
+    fn field_validation_range_requires_a_bound() {
         let input = quote!(
             #[derive(Form)]
-            #[form(component(action = "foo", name = MyFormData))]
-            pub struct MyFormData(Uuid);
+            pub struct SignUp {
+                #[form(validate(range()))]
+                pub age: u8,
+            }
         );
 
         let err = expect_err(derive_form(input));
 
-        assert_eq!(expected_err_msg, format!("{err}"));
+        assert_eq!("`range` requires at least one of `min` or `max`", format!("{err}"));
     }
 
     #[test]
-    #[cfg_attr(feature = "i18n", ignore)]
-    fn tuple_component_same_name_preceded_with_underscore_is_rejected() {
-        let expected_err_msg = r#"deriving Form for a tuple struct with `component` specified additionally requires a component name, consider using `component(name = "MyFormData_")`; note that "MyFormData" cannot be used as the component name because MyFormData is already an item defined in the function namespace and "_MyFormData" should not be used because of leptos name transformations"#;
+    fn struct_level_validation_is_generated() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(component, validate_with = passwords_match)]
+            pub struct SignUp {
+                pub password: String,
+                pub confirm: String,
+            }
+        );
+
+        let output = cleanup(&derive_form(input)?);
+
+        assert!(output.contains("fn __validate_with (signal : & __SignUpSignal , config : & __SignUpConfig)"));
+        assert!(output.contains("let errors = passwords_match (& value) . err () . unwrap_or_default () ;"));
+        assert!(output.contains("Self :: __validate_with (signal , config) ?"));
+        assert!(output.contains("SignUp :: __validate_with (& signal , & config)"));
+
+        Ok(())
+    }
 
+    #[test]
+    fn field_errors_are_collected_with_their_path() -> Result<(), Error> {
         let input = quote!(
             #[derive(Form)]
-            #[form(component(action = "foo", name = _MyFormData))]
-            pub struct MyFormData(Uuid);
+            pub struct Address {
+                pub street: String,
+                pub zip: u32,
+            }
         );
 
-        let err = expect_err(derive_form(input));
+        let output = cleanup(&derive_form(input)?);
 
-        assert_eq!(expected_err_msg, format!("{err}"));
+        assert!(output.contains("let mut errors = :: leptos_form :: FormErrors :: new () ;"));
+        assert!(output.contains(
+            "try_from_signal (signal . zip , & config . zip) . map_err (| err | err . in_field (\"zip\"))) ;"
+        ));
+        assert!(output.contains("let Some (_street_value) = _street_value else { return Err (errors . into ()) } ;"));
+        assert!(output
+            .contains("validate (& signal . zip , & config . zip) . map_err (| err | err . in_field (\"zip\"))) ;"));
+        assert!(output.contains("errors . into_result () ? ;"));
+
+        Ok(())
     }
 
     #[test]
-    #[cfg_attr(feature = "i18n", ignore)]
-    fn tuple_component_name_valid() -> Result<(), Error> {
+    fn wrapper_field_errors_keep_their_path() -> Result<(), Error> {
         let input = quote!(
             #[derive(Form)]
-            #[form(component(action = "foo", name = Test))]
-            pub struct MyFormData(Uuid);
+            #[form(wrapper)]
+            pub struct Zip(u32);
         );
 
-        derive_form(input)?;
+        let output = cleanup(&derive_form(input)?);
+
+        assert!(output.contains("let _0_value = errors . record (< u32 as"));
+        assert!(!output.contains("in_field"));
 
         Ok(())
     }
 
     #[test]
-    fn map_submit_rejects_non_path_or_closure() {
+    fn nested_errors_are_aggregated() -> Result<(), Error> {
         let input = quote!(
             #[derive(Form)]
-            #[form(component(
-                action = "foo",
-                map_submit = 1 + 1,
-            ))]
-            pub struct MyFormData {
-                pub id: Uuid,
+            #[form(component)]
+            pub struct Order {
+                pub addresses: Vec<Address>,
             }
         );
 
-        let err = expect_err(derive_form(input));
+        let output = cleanup(&derive_form(input)?);
 
-        assert_eq!("expected a path or a closure", format!("{err}"));
+        assert!(output
+            .contains("fn collect_errors (signal : & Self :: Signal , errors : & mut :: leptos_form :: FormErrors)"));
+        assert!(output.contains(
+            ":: errors (& signal . addresses) . into_iter () . map (| err | err . in_field (\"addresses\"))) ;"
+        ));
+        assert!(output.contains("provide_context (:: leptos_form :: components :: FormStatus {"));
+        assert!(output.contains(":: has_errors (& props . signal)"));
+
+        Ok(())
     }
 
     #[test]
-    fn map_submit_accepts_path() -> Result<(), Error> {
+    fn enums_reject_validate_with_attribute() {
         let input = quote!(
             #[derive(Form)]
-            #[form(component(
-                action = "foo",
-                map_submit = map_my_submit,
-            ))]
-            pub struct MyFormData {
-                pub id: Uuid,
+            #[form(validate_with = check)]
+            pub enum ShippingOption {
+                Standard,
+                Express,
             }
         );
 
-        derive_form(input)?;
+        let err = expect_err(derive_form(input));
 
-        Ok(())
+        assert_eq!("`validate_with` can only be used on structs", format!("{err}"));
     }
 
     #[test]
@@ -2717,6 +4185,244 @@ mod test {
         );
     }
 
+    #[test]
+    fn component_field_errors_attribute_cannot_be_used_with_a_string_literal_action() {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(component(action = "/api/my-form-data", field_errors))]
+            pub struct MyFormData {
+                pub ayo: u8,
+            }
+        );
+
+        let err = expect_err(derive_form(input));
+
+        assert_eq!(
+            "field_errors can only be specified on forms which specify an action using a server function",
+            format!("{err}")
+        );
+    }
+
+    #[test]
+    fn validate_on_is_set_on_field_props() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(component, validate_on = "blur")]
+            pub struct SignUp {
+                pub email: String,
+                #[form(validate_on = "input")]
+                pub username: String,
+            }
+        );
+
+        let output = cleanup(&derive_form(input)?);
+
+        assert!(output.contains(
+            ". validate_on (:: leptos_form :: ValidateOn :: Blur) . signal (props . signal . email . clone ())"
+        ));
+        assert!(output.contains(
+            ". validate_on (:: leptos_form :: ValidateOn :: Input) . signal (props . signal . username . clone ())"
+        ));
+        assert!(output.contains("fn touch (signal : & Self :: Signal)"));
+        assert!(output.contains(":: touch (& props . signal) ;"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_on_is_inherited_by_default() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            pub struct Address {
+                pub zip: String,
+            }
+        );
+
+        let output = cleanup(&derive_form(input)?);
+
+        assert!(output.contains(". validate_on (props . validate_on) . signal (props . signal . zip . clone ())"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn native_input_attrs_are_derived_from_validation_rules() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            pub struct SignUp {
+                #[form(validate(required, min_len = 3), autocomplete = "username")]
+                pub name: String,
+                #[form(validate(email))]
+                pub email: String,
+                pub age: u8,
+            }
+        );
+
+        let output = cleanup(&derive_form(input)?);
+
+        assert!(output.contains(
+            ". input_attrs (:: leptos_form :: InputAttrs { required : true , min_length : Some (3) , autocomplete : Some (:: leptos_form :: internal :: leptos :: prelude :: Oco :: Borrowed (\"username\")) , .. Default :: default () })"
+        ));
+        assert!(output.contains(
+            ". input_attrs (:: leptos_form :: InputAttrs { input_mode : Some (:: leptos_form :: internal :: leptos :: prelude :: Oco :: Borrowed (\"email\")) , .. Default :: default () })"
+        ));
+        assert_eq!(output.matches(". input_attrs (").count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn server_errors_are_routed_onto_fields() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(component(action = register(data), field_errors))]
+            pub struct SignUp {
+                pub email: String,
+                pub addresses: Vec<Address>,
+            }
+        );
+
+        let output = cleanup(&derive_form(input)?);
+
+        assert!(output.contains(":: leptos_form :: ToFormErrors :: to_form_errors (err)"));
+        assert!(output.contains(
+            "if let Err (error) = < SignUp as FormField < :: leptos_form :: internal :: leptos :: prelude :: AnyView >> :: set_error_at (& props . signal , & path . 0 , error)"
+        ));
+        assert!(
+            output.contains("Some ((:: leptos_form :: PathSegment :: Field (name) , path)) if name == \"email\" =>")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn multipart_forms_submit_form_data_to_the_server_fn() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(component(action = upload(data), multipart))]
+            pub struct Upload {
+                pub title: String,
+                pub files: FileUploads,
+            }
+        );
+
+        let output = cleanup(&derive_form(input)?);
+
+        assert!(output.contains("create_action (:: leptos_form :: multipart_server_fn (upload))"));
+        assert!(output.contains("action . dispatch (:: leptos_form :: multipart_form_data (& ev))"));
+        assert!(output.contains("submitting : Signal :: derive (move || action . pending () . get ())"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn multipart_forms_with_a_url_action_set_the_enctype() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(component(action = "/api/upload", multipart))]
+            pub struct Upload {
+                pub files: FileUploads,
+            }
+        );
+
+        let output = cleanup(&derive_form(input)?);
+
+        assert!(output.contains("method = \"post\" enctype = String :: from (\"multipart/form-data\")"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn component_multipart_attribute_requires_an_action() {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(component(multipart))]
+            pub struct Upload {
+                pub files: FileUploads,
+            }
+        );
+
+        let err = expect_err(derive_form(input));
+
+        assert_eq!(
+            "multipart can only be specified on forms which specify an action",
+            format!("{err}")
+        );
+    }
+
+    #[test]
+    fn enum_variants_are_redacted() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            pub enum Credentials {
+                Password { username: String, password: Secret },
+                Token(Secret),
+                Anonymous,
+            }
+        );
+
+        let output = cleanup(&derive_form(input)?);
+
+        assert!(output.contains("fn redact (& mut self) { match self {"));
+        assert!(output.contains("Self :: Password { username , password } =>"));
+        assert!(output.contains(":: redact (username) ;"));
+        assert!(output.contains(":: redact (password) ;"));
+        assert!(output.contains("Self :: Token (_0) =>"));
+        assert!(output.contains(":: redact (_0) ;"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn enum_default_value_and_errors_follow_the_selected_variant() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            pub enum PaymentMethod {
+                Card {
+                    number: String,
+                },
+                #[default]
+                Cash,
+            }
+        );
+
+        let output = cleanup(&derive_form(input)?);
+
+        assert!(
+            output.contains("if discriminant != \"Cash\" { return false ; }"),
+            "{output}"
+        );
+        assert!(output.contains(":: with_error (& signal . card , f) ,"), "{output}");
+        assert!(output.contains("_ => f (None) ,"), "{output}");
+        assert!(
+            output.contains("fn set_error (signal : & Self :: Signal , error : Option < :: leptos_form :: FormError >) { signal . discriminant . error . set (error) }"),
+            "{output}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn cached_values_are_redacted() -> Result<(), Error> {
+        let input = quote!(
+            #[derive(Form)]
+            #[form(component(action = sign_in(data), cache(value(LocalStorage(SerdeJson)))))]
+            pub struct SignIn {
+                pub email: String,
+                pub password: Secret,
+            }
+        );
+
+        let output = cleanup(&derive_form(input)?);
+
+        assert!(output.contains("fn redact (& mut self)"));
+        assert!(output.contains(":: redact (& mut self . email) ;"));
+        assert!(output.contains(":: redact (& mut self . password) ;"));
+        assert!(output.contains(":: redact (& mut value) ; cache . set_item (& cache_key , & value)"));
+
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(feature = "i18n", ignore)]
     fn component_is_produced_correctly() -> Result<(), Error> {
@@ -2,7 +2,7 @@
 
 use proc_macro::TokenStream;
 
-#[proc_macro_derive(Form, attributes(form, serde))]
+#[proc_macro_derive(Form, attributes(default, form, serde))]
 pub fn derive_form(tokens: TokenStream) -> TokenStream {
     match leptos_form_proc_macros_core::derive_form(tokens.into()) {
         Ok(tokens) => tokens.into(),